@@ -4,6 +4,7 @@ use ruroonga::commandable::Commandable;
 use ruroonga::queryable::Queryable;
 use ruroonga::dsl::*;
 use ruroonga::selectable::pseudo_table::PseudoTable;
+use ruroonga::selectable::labeled_drilldown_tree::LabeledDrilldownTree;
 
 fn advanced_drilldown_to_command() {
     let select = select("Memos".to_string());
@@ -36,7 +37,25 @@ fn advanced_drilldown_to_query() {
     println!("advanced drilldown: {}", builder);
 }
 
+fn advanced_drilldown_tree_to_command() {
+    let select = select("Memos".to_string());
+    let drilldown_tag = LabeledDrilldown::new("tag".to_string())
+        .keys(vec![("tag".to_string())])
+        .output_columns(vec![("_key".to_string()),
+                             ("_nsubrecs".to_string()),
+                             ("category".to_string())]);
+    let drilldown_category = LabeledDrilldown::new("category".to_string())
+        .keys(vec![("category".to_string())])
+        .output_columns(vec![("_key".to_string()), ("_nsubrecs".to_string())]);
+    let tree = LabeledDrilldownTree::new()
+        .root(drilldown_tag)
+        .child("tag".to_string(), drilldown_category);
+    let builder = (select + tree).unwrap().to_command();
+    println!("advanced drilldown tree: {}", builder);
+}
+
 fn main() {
     advanced_drilldown_to_command();
     advanced_drilldown_to_query();
+    advanced_drilldown_tree_to_command();
 }
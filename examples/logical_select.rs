@@ -0,0 +1,65 @@
+extern crate ruroonga_command as ruroonga;
+use ruroonga::selectable::drilldown::Drilldown;
+use ruroonga::selectable::labeled_drilldown::LabeledDrilldown;
+use ruroonga::selectable::staged_columns::StagedColumns;
+use ruroonga::selectable::staged_columns::WindowableColumn::Value;
+use ruroonga::commandable::Commandable;
+use ruroonga::queryable::Queryable;
+use ruroonga::dsl::*;
+use ruroonga::types::data_type::DataType;
+use ruroonga::types::stage_type::StageType;
+use ruroonga::types::groonga_time::GroongaTime;
+use ruroonga::types::time_range::TimeRange;
+use ruroonga::types::range_filter_type::RangeFilterType;
+
+fn range() -> TimeRange {
+    TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+                   GroongaTime::from_date(2016, 10, 20))
+        .max_border(RangeFilterType::Exclude)
+}
+
+fn drilldown_example() {
+    let select = logical_select("Logs".to_string(), "timestamp".to_string()).range(range());
+    let drilldown = Drilldown::new().drilldown(vec![("host".to_string())]);
+    let builder = (select + drilldown).to_query();
+    println!("drilldown: {}", builder);
+}
+
+fn labeled_drilldowns_example() {
+    let select = logical_select("Logs".to_string(), "timestamp".to_string())
+        .filter("message @ \"error\"".to_string());
+    let drilldowns = vec![LabeledDrilldown::new("host".to_string())
+                              .keys(vec![("host".to_string())]),
+                          LabeledDrilldown::new("level".to_string())
+                              .keys(vec![("level".to_string())])
+                              .filter("_nsubrecs > 10".to_string())];
+    let builder = (select + drilldowns).to_command();
+    println!("labeled drilldowns: {}", builder);
+}
+
+fn staged_columns_example() {
+    let select = logical_select("Logs".to_string(), "timestamp".to_string())
+        .post_filter("elapsed_msec > 1000".to_string());
+    let staged_columns = StagedColumns::new("elapsed_msec".to_string(),
+                                            StageType::Initial,
+                                            DataType::UInt32,
+                                            Value("'elapsed / 1000'".to_string()));
+    let builder = (select + staged_columns).unwrap().to_command();
+    println!("staged columns: {}", builder);
+}
+
+fn logical_count_example() {
+    let count = logical_count("Logs".to_string(), "timestamp".to_string())
+        .range(range())
+        .query("error".to_string())
+        .match_columns(vec![("message".to_string())])
+        .to_command();
+    println!("logical_count: {}", count);
+}
+
+fn main() {
+    drilldown_example();
+    labeled_drilldowns_example();
+    staged_columns_example();
+    logical_count_example();
+}
@@ -4,12 +4,13 @@ use ruroonga::commandable::Commandable;
 use ruroonga::dsl::*;
 use ruroonga::types::data_type::DataType;
 use ruroonga::types::column_flag_type::ColumnFlagType;
+use ruroonga::types::stage_type::StageType;
 use ruroonga::selectable::staged_columns::WindowableColumn::{Window, Value};
 use ruroonga::selectable::staged_columns::WindowFunction::RecordNumber;
 
 fn staged_columns_to_command() {
     let label = "filtered".to_string();
-    let stage = "filtered".to_string();
+    let stage = StageType::Filtered;
     let value = "'_id'".to_string();
     let select = select("Items".to_string())
         .filter("price < 1200".to_string())
@@ -22,13 +23,13 @@ fn staged_columns_to_command() {
                                             DataType::UInt32,
                                             Value(value.clone()))
         .flags(vec![(ColumnFlagType::Scalar)]);
-    let builder = (select + staged_columns).to_command();
+    let builder = (select + staged_columns).unwrap().to_command();
     println!("staged columns: {}", builder);
 }
 
 fn staged_columns_with_window_function() {
     let label = "nth_record".to_string();
-    let stage = "initial".to_string();
+    let stage = StageType::Initial;
     let select = select("Items".to_string()).output_columns(vec![("_id".to_string()),
                                                                  ("_key".to_string()),
                                                                  ("price".to_string()),
@@ -39,11 +40,29 @@ fn staged_columns_with_window_function() {
                                             Window(RecordNumber))
         .flags(vec![(ColumnFlagType::Scalar)])
         .sort_keys(vec![("-price".to_string())]);
-    let builder = (select + staged_columns).to_command();
+    let builder = (select + staged_columns).unwrap().to_command();
     println!("staged columns with window function: {}", builder);
 }
 
+fn staged_columns_sequence_to_command() {
+    let select = select("Items".to_string()).output_columns(vec![("_key".to_string()),
+                                                                 ("price".to_string()),
+                                                                 ("discounted".to_string()),
+                                                                 ("tax_included".to_string())]);
+    let tax_included = StagedColumns::new("tax_included".to_string(),
+                                          StageType::Output,
+                                          DataType::UInt32,
+                                          Value("'discounted * 1.08'".to_string()));
+    let discounted = StagedColumns::new("discounted".to_string(),
+                                        StageType::Initial,
+                                        DataType::UInt32,
+                                        Value("'price * 0.9'".to_string()));
+    let builder = (select + vec![(tax_included), (discounted)]).unwrap().to_command();
+    println!("staged columns sequence: {}", builder);
+}
+
 fn main() {
     staged_columns_to_command();
     staged_columns_with_window_function();
+    staged_columns_sequence_to_command();
 }
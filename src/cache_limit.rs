@@ -63,6 +63,7 @@ impl Commandable for CacheLimitCommand {
 extendable!(CacheLimitCommand);
 request_cancellable!(CacheLimitCommand);
 request_timeoutable!(CacheLimitCommand);
+version_checkable!(CacheLimitCommand);
 
 #[cfg(test)]
 mod test {
@@ -78,6 +78,7 @@ impl Commandable for ColumnCopyCommand {
 extendable!(ColumnCopyCommand);
 request_cancellable!(ColumnCopyCommand);
 request_timeoutable!(ColumnCopyCommand);
+version_checkable!(ColumnCopyCommand);
 
 #[cfg(test)]
 mod test {
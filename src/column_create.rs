@@ -1,8 +1,8 @@
 use command::{Command, Query};
 use command::Command::ColumnCreate;
 use std::collections::HashMap;
-use types::data_type::DataType;
-use types::column_flag_type::ColumnFlagType;
+use types::column_type::ColumnType;
+use types::column_flag_type::{self, ColumnFlagType, ColumnFlagsError};
 use util;
 use command_query::CommandQuery;
 use queryable::Queryable;
@@ -11,6 +11,8 @@ use commandable::Commandable;
 use extendable::Extendable;
 use request_cancellable::RequestCancellable;
 use request_timeoutable::RequestTimeoutable;
+use plugin_requirable::PluginRequirable;
+use types::plugin::Plugin;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct ColumnCreateCommand {
@@ -18,6 +20,7 @@ pub struct ColumnCreateCommand {
     table: String,
     name: String,
     arguments: HashMap<String, String>,
+    required_flags: Vec<ColumnFlagType>,
 }
 
 impl Default for ColumnCreateCommand {
@@ -27,6 +30,7 @@ impl Default for ColumnCreateCommand {
             table: "".to_string(),
             name: "".to_string(),
             arguments: HashMap::new(),
+            required_flags: vec![],
         }
     }
 }
@@ -40,7 +44,19 @@ impl ColumnCreateCommand {
         }
     }
 
-    pub fn column_type(mut self, column_type: DataType) -> ColumnCreateCommand {
+    /// Accepts `DataType` and `ColumnType`.
+    ///
+    /// Flags which a vector type requires are appended to `flags` on `build()`.
+    /// Conflicting flags in `flags` are kept so that `validate()` reports them.
+    pub fn column_type<T>(mut self, column_type: T) -> ColumnCreateCommand
+        where T: Into<ColumnType>
+    {
+        let column_type = column_type.into();
+        self.required_flags = if column_type.is_vector() {
+            column_type.flags()
+        } else {
+            vec![]
+        };
         let string = format!("{}", column_type);
         self.arguments.insert("type".to_string(), string.to_owned());
         self
@@ -61,12 +77,56 @@ impl ColumnCreateCommand {
         self
     }
 
+    /// Specifies source columns of an index column.
+    ///
+    /// Multiple sources need `WithSection` flag.
+    pub fn sources(mut self, sources: Vec<String>) -> ColumnCreateCommand {
+        let string = util::split_values_vec(sources);
+        self.arguments.insert("source".to_string(), string.to_owned());
+        self
+    }
+
+    /// Checks a combination of `flags`.
+    ///
+    /// See `column_flag_type::validate_flags` for the rules.
+    pub fn validate(&self) -> Result<(), ColumnFlagsError> {
+        column_flag_type::validate_flags(&self.merged_flags())
+    }
+
+    // Column kind flags in `flags` come first, then flags which the column type requires
+    // and the rest of `flags`. Conflicting kinds are kept for `validate()`.
+    fn merged_flags(&self) -> Vec<ColumnFlagType> {
+        let flags: Vec<ColumnFlagType> = match self.arguments.get("flags") {
+            Some(flags) => flags.split('|').filter_map(|flag| flag.parse().ok()).collect(),
+            None => vec![],
+        };
+        let (kinds, others): (Vec<ColumnFlagType>, Vec<ColumnFlagType>) =
+            flags.into_iter().partition(|flag| {
+                [ColumnFlagType::Scalar, ColumnFlagType::Vector, ColumnFlagType::Index]
+                    .contains(flag)
+            });
+        let mut merged = vec![];
+        for flag in kinds.into_iter().chain(self.required_flags.clone()).chain(others) {
+            if !merged.contains(&flag) {
+                merged.push(flag);
+            }
+        }
+        merged
+    }
+
     pub fn build(self) -> (Command, Query) {
+        let flags = self.merged_flags();
         let mut query: Query = vec![("table".to_string(), self.table),
                                     ("name".to_string(), self.name)];
         for (key, value) in &self.arguments {
+            if key == "flags" {
+                continue;
+            }
             query.push((key.to_owned(), value.to_owned()));
         }
+        if !flags.is_empty() {
+            query.push(("flags".to_string(), util::split_flags_vec(flags)));
+        }
         (ColumnCreate, query)
     }
 }
@@ -90,6 +150,14 @@ impl Commandable for ColumnCreateCommand {
 extendable!(ColumnCreateCommand);
 request_cancellable!(ColumnCreateCommand);
 request_timeoutable!(ColumnCreateCommand);
+version_checkable!(ColumnCreateCommand);
+
+/// Bundled column types and flags do not depend on any plugins.
+impl PluginRequirable for ColumnCreateCommand {
+    fn required_plugins(&self) -> Vec<Plugin> {
+        vec![]
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -97,11 +165,13 @@ mod test {
     use command::Command::ColumnCreate;
     use std::collections::HashMap;
     use types::data_type::DataType;
-    use types::column_flag_type::ColumnFlagType;
+    use types::column_type::ColumnType;
+    use types::column_flag_type::{ColumnFlagType, ColumnFlagsError};
     use command::Query;
     use queryable::Queryable;
     use commandable::Commandable;
     use extendable::Extendable;
+    use std::str::FromStr;
 
     #[test]
     fn test_new() {
@@ -112,6 +182,7 @@ mod test {
             table: "Test".to_string(),
             name: "element".to_string(),
             arguments: HashMap::new(),
+            required_flags: vec![],
         };
         assert_eq!(expected, vanilla_column_create);
     }
@@ -127,6 +198,84 @@ mod test {
             table: "Test".to_string(),
             name: "element".to_string(),
             arguments: arg,
+            required_flags: vec![],
+        };
+        assert_eq!(expected, column_create);
+    }
+
+    #[test]
+    fn test_column_type_with_reference_vector() {
+        let tags = ColumnType::ReferenceVector {
+            table: "Tags".to_string(),
+            with_weight: true,
+        };
+        let column_create = ColumnCreateCommand::new("Entries".to_string(), "tags".to_string())
+            .column_type(tags.clone());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("type".to_string(), "Tags".to_string());
+        let expected = ColumnCreateCommand {
+            command: ColumnCreate,
+            table: "Entries".to_string(),
+            name: "tags".to_string(),
+            arguments: arg,
+            required_flags: vec![ColumnFlagType::Vector, ColumnFlagType::WithWeight],
+        };
+        assert_eq!(expected, column_create);
+        let flags = ("flags".to_string(), "COLUMN_VECTOR|WITH_WEIGHT".to_string());
+        assert!(column_create.build().1.contains(&flags));
+        let flags_first = ColumnCreateCommand::new("Entries".to_string(), "tags".to_string())
+            .flags(vec![ColumnFlagType::Vector])
+            .column_type(tags.clone())
+            .build();
+        assert!(flags_first.1.contains(&flags));
+        let type_first = ColumnCreateCommand::new("Entries".to_string(), "tags".to_string())
+            .column_type(tags)
+            .flags(vec![ColumnFlagType::WithWeight])
+            .build();
+        assert!(type_first.1.contains(&flags));
+    }
+
+    #[test]
+    fn test_column_type_with_vector() {
+        let (_, query) = ColumnCreateCommand::new("Entries".to_string(), "tags".to_string())
+            .column_type(ColumnType::Vector(DataType::ShortText))
+            .flags(vec![ColumnFlagType::MissingIgnore])
+            .build();
+        assert!(query.contains(&("type".to_string(), "ShortText".to_string())));
+        assert!(query.contains(&("flags".to_string(),
+                                 "COLUMN_VECTOR|MISSING_IGNORE".to_string())));
+        let (_, query) = ColumnCreateCommand::new("Entries".to_string(), "title".to_string())
+            .column_type(DataType::ShortText)
+            .build();
+        assert!(!query.iter().any(|&(ref key, _)| key == "flags"));
+    }
+
+    #[test]
+    fn test_validate_column_type_with_conflicting_flags() {
+        let column_create = ColumnCreateCommand::new("Entries".to_string(), "tags".to_string())
+            .column_type(ColumnType::ReferenceVector {
+                table: "Tags".to_string(),
+                with_weight: false,
+            })
+            .flags(vec![ColumnFlagType::Index]);
+        assert_eq!(Err(ColumnFlagsError::Conflict(ColumnFlagType::Index, ColumnFlagType::Vector)),
+                   column_create.validate());
+        let (_, query) = column_create.build();
+        assert!(query.contains(&("flags".to_string(), "COLUMN_INDEX|COLUMN_VECTOR".to_string())));
+    }
+
+    #[test]
+    fn test_sources() {
+        let column_create = ColumnCreateCommand::new("Terms".to_string(), "index".to_string())
+            .sources(vec![("title".to_string()), ("content".to_string())]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("source".to_string(), "title,content".to_string());
+        let expected = ColumnCreateCommand {
+            command: ColumnCreate,
+            table: "Terms".to_string(),
+            name: "index".to_string(),
+            arguments: arg,
+            required_flags: vec![],
         };
         assert_eq!(expected, column_create);
     }
@@ -142,10 +291,31 @@ mod test {
             table: "Test".to_string(),
             name: "element".to_string(),
             arguments: arg,
+            required_flags: vec![],
         };
         assert_eq!(expected, column_create);
     }
 
+    #[test]
+    fn test_validate() {
+        let index = ColumnCreateCommand::new("Terms".to_string(), "index".to_string())
+            .flags(vec![ColumnFlagType::Index,
+                        ColumnFlagType::WithPosition,
+                        ColumnFlagType::IndexMedium]);
+        assert_eq!(Ok(()), index.validate());
+        let scalar = ColumnCreateCommand::new("Entries".to_string(), "title".to_string())
+            .column_type(DataType::ShortText);
+        assert_eq!(Ok(()), scalar.validate());
+        let invalid = ColumnCreateCommand::new("Entries".to_string(), "title".to_string())
+            .flags(vec![ColumnFlagType::Scalar, ColumnFlagType::WithPosition]);
+        assert_eq!(Err(ColumnFlagsError::Requires(ColumnFlagType::WithPosition,
+                                                  ColumnFlagType::Index)),
+                   invalid.validate());
+        let compressed_index = ColumnCreateCommand::new("Terms".to_string(), "index".to_string())
+            .flags(vec![ColumnFlagType::Index, FromStr::from_str("COMPRESS_ZLIB").unwrap()]);
+        assert!(compressed_index.validate().is_err());
+    }
+
     #[test]
     fn test_source() {
         let column_create = ColumnCreateCommand::new("Test".to_string(), "element".to_string())
@@ -157,6 +327,7 @@ mod test {
             table: "Test".to_string(),
             name: "element".to_string(),
             arguments: arg,
+            required_flags: vec![],
         };
         assert_eq!(expected, column_create);
     }
@@ -200,6 +371,7 @@ mod test {
             table: "Test".to_string(),
             name: "element".to_string(),
             arguments: arg.to_owned(),
+            required_flags: vec![],
         };
         let query = ColumnCreateCommand::new("Test".to_string(), "element".to_string());
         unsafe {
@@ -12,6 +12,7 @@ use request_timeoutable::RequestTimeoutable;
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct ColumnListCommand {
     command: Command,
+    table: String,
     arguments: HashMap<String, String>,
 }
 
@@ -19,18 +20,19 @@ impl Default for ColumnListCommand {
     fn default() -> ColumnListCommand {
         ColumnListCommand {
             command: ColumnList,
+            table: "".to_string(),
             arguments: HashMap::new(),
         }
     }
 }
 
 impl ColumnListCommand {
-    pub fn new() -> ColumnListCommand {
-        Default::default()
+    pub fn new(table: String) -> ColumnListCommand {
+        ColumnListCommand { table: table, ..ColumnListCommand::default() }
     }
 
     pub fn build(self) -> (Command, Query) {
-        let mut query: Query = vec![];
+        let mut query: Query = vec![("table".to_string(), self.table)];
         for (key, value) in &self.arguments {
             query.push((key.to_owned(), value.to_owned()));
         }
@@ -57,6 +59,7 @@ impl Commandable for ColumnListCommand {
 extendable!(ColumnListCommand);
 request_cancellable!(ColumnListCommand);
 request_timeoutable!(ColumnListCommand);
+version_checkable!(ColumnListCommand);
 
 #[cfg(test)]
 mod test {
@@ -70,9 +73,10 @@ mod test {
 
     #[test]
     fn test_new() {
-        let column_list = ColumnListCommand::new();
+        let column_list = ColumnListCommand::new("Entries".to_string());
         let expected = ColumnListCommand {
             command: ColumnList,
+            table: "Entries".to_string(),
             arguments: HashMap::new(),
         };
         assert_eq!(expected, column_list);
@@ -80,23 +84,23 @@ mod test {
 
     #[test]
     fn test_build() {
-        let actual = ColumnListCommand::new().build();
-        let expected_query: Query = vec![];
+        let actual = ColumnListCommand::new("Entries".to_string()).build();
+        let expected_query: Query = vec![("table".to_string(), "Entries".to_string())];
         let expected = (ColumnList, expected_query);
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_queryable() {
-        let query = ColumnListCommand::new().to_query();
-        let url_encoded = "/d/column_list?";
+        let query = ColumnListCommand::new("Entries".to_string()).to_query();
+        let url_encoded = "/d/column_list?table=Entries";
         assert_eq!(url_encoded.to_string(), query);
     }
 
     #[test]
     fn test_commandable() {
-        let query = ColumnListCommand::new().to_command();
-        let cli_encoded = "column_list";
+        let query = ColumnListCommand::new("Entries".to_string()).to_command();
+        let cli_encoded = "column_list --table Entries";
         assert_eq!(cli_encoded.to_string(), query);
     }
 
@@ -106,9 +110,10 @@ mod test {
         arg.insert("user".to_string(), "defined".to_string());
         let expected = ColumnListCommand {
             command: ColumnList,
+            table: "Entries".to_string(),
             arguments: arg.to_owned(),
         };
-        let query = ColumnListCommand::new();
+        let query = ColumnListCommand::new("Entries".to_string());
         unsafe {
             let extended = query.set_arguments(arg.to_owned());
             assert_eq!(expected, extended);
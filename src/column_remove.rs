@@ -66,6 +66,7 @@ impl Commandable for ColumnRemoveCommand {
 extendable!(ColumnRemoveCommand);
 request_cancellable!(ColumnRemoveCommand);
 request_timeoutable!(ColumnRemoveCommand);
+version_checkable!(ColumnRemoveCommand);
 
 #[cfg(test)]
 mod test {
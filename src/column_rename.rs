@@ -66,6 +66,7 @@ impl Commandable for ColumnRenameCommand {
 extendable!(ColumnRenameCommand);
 request_cancellable!(ColumnRenameCommand);
 request_timeoutable!(ColumnRenameCommand);
+version_checkable!(ColumnRenameCommand);
 
 #[cfg(test)]
 mod test {
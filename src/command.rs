@@ -3,12 +3,13 @@ use std::str::FromStr;
 use std::convert::AsRef;
 use self::Command::{Select, Load, Status, CacheLimit, Dump, DatabaseUnmap, Delete, IoFlush,
                     LogLevel, LogPut, LogReopen, TableCreate, TableRename, TableRemove, TableList,
-                    ColumnCopy, ColumnCreate, ColumnList, ColumnRename, ColumnRemove, Reindex,
-                    RequestCancel, Normalize, NormalizerList, ObjectExist, ObjectInspect,
-                    ObjectRemove, PluginRegister, PluginUnregister, ThreadLimit, Tokenize,
-                    TokenizerList, Truncate, Schema, Shutdown, Extension};
+                    TableTokenize, ColumnCopy, ColumnCreate, ColumnList, ColumnRename,
+                    ColumnRemove, Reindex, RequestCancel, Normalize, NormalizerList, ObjectExist,
+                    ObjectInspect, ObjectRemove, PluginRegister, PluginUnregister, ThreadLimit,
+                    Tokenize, TokenizerList, Truncate, Schema, Shutdown, Extension};
 #[cfg(feature="sharding")]
-use self::Command::{LogicalCount, LogicalSelect, LogicalShardList, LogicalTableRemove};
+use self::Command::{LogicalCount, LogicalRangeFilter, LogicalSelect, LogicalShardList,
+                    LogicalTableRemove};
 
 #[derive (Debug)]
 pub enum CommandError {
@@ -32,6 +33,7 @@ pub enum Command {
     TableList,
     TableRename,
     TableRemove,
+    TableTokenize,
     ColumnCopy,
     ColumnCreate,
     ColumnList,
@@ -55,6 +57,8 @@ pub enum Command {
     #[cfg(feature="sharding")]
     LogicalCount,
     #[cfg(feature="sharding")]
+    LogicalRangeFilter,
+    #[cfg(feature="sharding")]
     LogicalSelect,
     #[cfg(feature="sharding")]
     LogicalShardList,
@@ -91,6 +95,7 @@ impl AsRef<str> for Command {
             TableList => "table_list",
             TableRename => "table_rename",
             TableRemove => "table_remove",
+            TableTokenize => "table_tokenize",
             ColumnCopy => "column_copy",
             ColumnCreate => "column_create",
             ColumnList => "column_list",
@@ -114,6 +119,8 @@ impl AsRef<str> for Command {
             #[cfg(feature="sharding")]
             LogicalCount => "logical_count",
             #[cfg(feature="sharding")]
+            LogicalRangeFilter => "logical_range_filter",
+            #[cfg(feature="sharding")]
             LogicalSelect => "logical_select",
             #[cfg(feature="sharding")]
             LogicalShardList => "logical_shard_list",
@@ -146,6 +153,7 @@ impl FromStr for Command {
                 "table_list" => TableList,
                 "table_rename" => TableRename,
                 "table_remove" => TableRemove,
+                "table_tokenize" => TableTokenize,
                 "column_copy" => ColumnCopy,
                 "column_create" => ColumnCreate,
                 "column_list" => ColumnList,
@@ -169,6 +177,8 @@ impl FromStr for Command {
                 #[cfg(feature="sharding")]
                 "logical_count" => LogicalCount,
                 #[cfg(feature="sharding")]
+                "logical_range_filter" => LogicalRangeFilter,
+                #[cfg(feature="sharding")]
                 "logical_select" => LogicalSelect,
                 #[cfg(feature="sharding")]
                 "logical_shard_list" => LogicalShardList,
@@ -198,6 +208,7 @@ impl fmt::Display for Command {
             TableList => "table_list",
             TableRename => "table_rename",
             TableRemove => "table_remove",
+            TableTokenize => "table_tokenize",
             ColumnCopy => "column_copy",
             ColumnCreate => "column_create",
             ColumnList => "column_list",
@@ -221,6 +232,8 @@ impl fmt::Display for Command {
             #[cfg(feature="sharding")]
             LogicalCount => "logical_count",
             #[cfg(feature="sharding")]
+            LogicalRangeFilter => "logical_range_filter",
+            #[cfg(feature="sharding")]
             LogicalSelect => "logical_select",
             #[cfg(feature="sharding")]
             LogicalShardList => "logical_shard_list",
@@ -241,13 +254,14 @@ mod test {
     use super::Command;
     use super::Command::{Select, Load, Status, CacheLimit, Dump, DatabaseUnmap, Delete, IoFlush,
                          LogLevel, LogPut, LogReopen, TableCreate, TableList, TableRemove,
-                         TableRename, ColumnCopy, ColumnCreate, ColumnList, ColumnRename,
-                         ColumnRemove, Reindex, RequestCancel, ObjectExist, ObjectInspect,
-                         ObjectRemove, Normalize, NormalizerList, PluginRegister,
+                         TableRename, TableTokenize, ColumnCopy, ColumnCreate, ColumnList,
+                         ColumnRename, ColumnRemove, Reindex, RequestCancel, ObjectExist,
+                         ObjectInspect, ObjectRemove, Normalize, NormalizerList, PluginRegister,
                          PluginUnregister, ThreadLimit, Tokenize, TokenizerList, Truncate, Schema,
                          Shutdown, Extension};
     #[cfg(feature="sharding")]
-    use super::Command::{LogicalCount, LogicalSelect, LogicalShardList, LogicalTableRemove};
+    use super::Command::{LogicalCount, LogicalRangeFilter, LogicalSelect, LogicalShardList,
+                         LogicalTableRemove};
 
     #[test]
     fn test_from_str() {
@@ -269,6 +283,7 @@ mod test {
         assert_eq!(TableRename, FromStr::from_str("table_rename").unwrap());
         assert_eq!(TableRemove, FromStr::from_str("table_remove").unwrap());
         assert_eq!(TableList, FromStr::from_str("table_list").unwrap());
+        assert_eq!(TableTokenize, FromStr::from_str("table_tokenize").unwrap());
         assert_eq!(ObjectExist, FromStr::from_str("object_exist").unwrap());
         assert_eq!(ObjectInspect, FromStr::from_str("object_inspect").unwrap());
         assert_eq!(Normalize, FromStr::from_str("normalize").unwrap());
@@ -297,6 +312,8 @@ mod test {
     #[test]
     fn test_from_str_with_sharding() {
         assert_eq!(LogicalCount, FromStr::from_str("logical_count").unwrap());
+        assert_eq!(LogicalRangeFilter,
+                   FromStr::from_str("logical_range_filter").unwrap());
         assert_eq!(LogicalSelect, FromStr::from_str("logical_select").unwrap());
         assert_eq!(LogicalShardList,
                    FromStr::from_str("logical_shard_list").unwrap());
@@ -324,6 +341,7 @@ mod test {
         assert_eq!("table_list".to_owned(), format!("{}", TableList));
         assert_eq!("table_rename".to_owned(), format!("{}", TableRename));
         assert_eq!("table_remove".to_owned(), format!("{}", TableRemove));
+        assert_eq!("table_tokenize".to_owned(), format!("{}", TableTokenize));
         assert_eq!("object_exist".to_owned(), format!("{}", ObjectExist));
         assert_eq!("object_inspect".to_owned(), format!("{}", ObjectInspect));
         assert_eq!("object_remove".to_owned(), format!("{}", ObjectRemove));
@@ -346,6 +364,8 @@ mod test {
     #[test]
     fn test_fmt_with_sharding() {
         assert_eq!("logical_count".to_owned(), format!("{}", LogicalCount));
+        assert_eq!("logical_range_filter".to_owned(),
+                   format!("{}", LogicalRangeFilter));
         assert_eq!("logical_select".to_owned(), format!("{}", LogicalSelect));
         assert_eq!("logical_shard_list".to_owned(),
                    format!("{}", LogicalShardList));
@@ -370,6 +390,7 @@ mod test {
         assert_eq!(TableList.as_ref(), "table_list");
         assert_eq!(TableRename.as_ref(), "table_rename");
         assert_eq!(TableRemove.as_ref(), "table_remove");
+        assert_eq!(TableTokenize.as_ref(), "table_tokenize");
         assert_eq!(ColumnCopy.as_ref(), "column_copy");
         assert_eq!(ColumnCreate.as_ref(), "column_create");
         assert_eq!(ColumnList.as_ref(), "column_list");
@@ -398,6 +419,7 @@ mod test {
     #[test]
     fn test_as_str_with_sharding() {
         assert_eq!(LogicalCount.as_ref(), "logical_count");
+        assert_eq!(LogicalRangeFilter.as_ref(), "logical_range_filter");
         assert_eq!(LogicalSelect.as_ref(), "logical_select");
         assert_eq!(LogicalShardList.as_ref(), "logical_shard_list");
         assert_eq!(LogicalTableRemove.as_ref(), "logical_table_remove");
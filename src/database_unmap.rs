@@ -57,6 +57,7 @@ impl Commandable for DatabaseUnmapCommand {
 extendable!(DatabaseUnmapCommand);
 request_cancellable!(DatabaseUnmapCommand);
 request_timeoutable!(DatabaseUnmapCommand);
+version_checkable!(DatabaseUnmapCommand);
 
 #[cfg(test)]
 mod test {
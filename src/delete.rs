@@ -76,6 +76,7 @@ impl Commandable for DeleteCommand {
 extendable!(DeleteCommand);
 request_cancellable!(DeleteCommand);
 request_timeoutable!(DeleteCommand);
+version_checkable!(DeleteCommand);
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,130 @@
+use delete::DeleteCommand;
+use select::SelectCommand;
+use response::select::SelectIdsResponse;
+
+/// Deletes records which match a filter in batches.
+///
+/// `preview` returns `select` to list IDs of affected records.
+/// `batches` converts its decoded result into `delete` commands for each record,
+/// so that deletions can be audited and rate-limited between batches.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::delete_batcher::DeleteBatcher;
+/// use ruroonga_command::commandable::Commandable;
+/// use ruroonga_command::response::select::SelectIdsResponse;
+/// let batcher = DeleteBatcher::new("Logs".to_string(), "level == \"debug\"".to_string())
+///                   .batch_size(2);
+/// println!("{}", batcher.preview().to_command());
+/// let json = r#"[[[3], [["_id", "UInt32"]], [1], [4], [5]]]"#;
+/// let preview: SelectIdsResponse = json.parse().unwrap();
+/// let batches = batcher.batches(&preview);
+/// assert_eq!(2, batches.len());
+/// for delete in batches[0].clone() {
+///     println!("{}", delete.to_command());
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeleteBatcher {
+    table: String,
+    filter: String,
+    limit: i64,
+    batch_size: usize,
+}
+
+impl DeleteBatcher {
+    pub fn new(table: String, filter: String) -> DeleteBatcher {
+        DeleteBatcher {
+            table: table,
+            filter: filter,
+            limit: -1,
+            batch_size: 100,
+        }
+    }
+
+    /// The maximum number of previewed records. All matched records are previewed by default.
+    pub fn limit(mut self, limit: i64) -> DeleteBatcher {
+        self.limit = limit;
+        self
+    }
+
+    /// The number of `delete` commands in a batch. It is 100 by default.
+    pub fn batch_size(mut self, batch_size: usize) -> DeleteBatcher {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Returns `select` which lists `_id` of records to be deleted.
+    pub fn preview(&self) -> SelectCommand {
+        SelectCommand::new(self.table.clone())
+            .filter(self.filter.clone())
+            .output_columns(vec!["_id".to_string()])
+            .limit(self.limit)
+    }
+
+    /// Whether `preview` covers all matched records.
+    /// Otherwise, the rest of records remain after running all batches.
+    pub fn is_complete(&self, preview: &SelectIdsResponse) -> bool {
+        preview.ids.len() as u64 >= preview.n_hits
+    }
+
+    /// Returns `delete` commands for previewed records which are split into batches.
+    pub fn batches(&self, preview: &SelectIdsResponse) -> Vec<Vec<DeleteCommand>> {
+        let batch_size = if self.batch_size == 0 { 1 } else { self.batch_size };
+        preview.ids
+            .chunks(batch_size)
+            .map(|ids| {
+                ids.iter()
+                    .map(|&id| DeleteCommand::new(self.table.clone()).id(id))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use delete::DeleteCommand;
+    use select::SelectCommand;
+    use response::select::SelectIdsResponse;
+
+    fn preview(n_hits: u64, ids: Vec<u64>) -> SelectIdsResponse {
+        SelectIdsResponse {
+            n_hits: n_hits,
+            ids: ids,
+        }
+    }
+
+    #[test]
+    fn test_preview() {
+        let batcher = DeleteBatcher::new("Logs".to_string(), "level == \"debug\"".to_string())
+            .limit(1000);
+        let expected = SelectCommand::new("Logs".to_string())
+            .filter("level == \"debug\"".to_string())
+            .output_columns(vec!["_id".to_string()])
+            .limit(1000);
+        assert_eq!(expected, batcher.preview());
+    }
+
+    #[test]
+    fn test_batches() {
+        let batcher = DeleteBatcher::new("Logs".to_string(), "level == \"debug\"".to_string())
+            .batch_size(2);
+        let batches = batcher.batches(&preview(3, vec![1, 4, 5]));
+        let expected = vec![vec![DeleteCommand::new("Logs".to_string()).id(1),
+                                 DeleteCommand::new("Logs".to_string()).id(4)],
+                            vec![DeleteCommand::new("Logs".to_string()).id(5)]];
+        assert_eq!(expected, batches);
+        assert!(batcher.batches(&preview(0, vec![])).is_empty());
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let batcher = DeleteBatcher::new("Logs".to_string(), "level == \"debug\"".to_string())
+            .limit(2);
+        assert!(batcher.is_complete(&preview(2, vec![1, 4])));
+        assert!(!batcher.is_complete(&preview(3, vec![1, 4])));
+    }
+}
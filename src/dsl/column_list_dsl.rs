@@ -1,7 +1,7 @@
 use column_list::ColumnListCommand;
 
-pub fn column_list() -> ColumnListCommand {
-    ColumnListCommand::new()
+pub fn column_list(table: String) -> ColumnListCommand {
+    ColumnListCommand::new(table)
 }
 
 #[cfg(test)]
@@ -11,8 +11,8 @@ mod test {
 
     #[test]
     fn test_column_list() {
-        let syntax = column_list();
-        let actual = ColumnListCommand::new();
+        let syntax = column_list("Entries".to_string());
+        let actual = ColumnListCommand::new("Entries".to_string());
         assert_eq!(syntax, actual);
     }
 }
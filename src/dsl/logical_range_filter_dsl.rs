@@ -0,0 +1,20 @@
+use logical_range_filter::LogicalRangeFilterCommand;
+
+pub fn logical_range_filter(logical_table: String,
+                            shard_key: String)
+                            -> LogicalRangeFilterCommand {
+    LogicalRangeFilterCommand::new(logical_table, shard_key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use logical_range_filter::LogicalRangeFilterCommand;
+
+    #[test]
+    fn test_logical_range_filter() {
+        let syntax = logical_range_filter("Logs".to_string(), "timestamp".to_string());
+        let actual = LogicalRangeFilterCommand::new("Logs".to_string(), "timestamp".to_string());
+        assert_eq!(syntax, actual);
+    }
+}
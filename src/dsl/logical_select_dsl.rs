@@ -8,6 +8,17 @@ pub fn logical_select(logical_table: String, shard_key: String) -> LogicalSelect
 mod test {
     use super::*;
     use logical_select::LogicalSelectCommand;
+    use selectable::drilldown::Drilldown;
+    use selectable::drilldown_builder::DrilldownBuilder;
+    use selectable::labeled_drilldown::LabeledDrilldown;
+    use selectable::labeled_drilldown_builder::LabeledDrilldownBuilder;
+    use selectable::labeled_drilldown_sequence_builder::LabeledDrilldownSequenceBuilder;
+    use selectable::staged_columns::StagedColumns;
+    use selectable::staged_columns::WindowableColumn::Value;
+    use selectable::staged_columns_builder::StagedColumnsBuilder;
+    use selectable::drilldown_type::DrilldownUsable;
+    use types::data_type::DataType;
+    use types::stage_type::StageType;
 
     #[test]
     fn test_logical_select() {
@@ -15,4 +26,49 @@ mod test {
         let actual = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string());
         assert_eq!(syntax, actual);
     }
+
+    #[test]
+    fn test_logical_select_with_drilldown() {
+        let syntax = logical_select("Entries".to_string(), "created_at".to_string());
+        let drilldown = Drilldown::new().drilldown(vec![("tag".to_string())]);
+        let expected = DrilldownBuilder::new(DrilldownUsable::LogicalSelect(syntax.clone()),
+                                             drilldown.clone());
+        assert_eq!(expected.build(), (syntax + drilldown).build());
+    }
+
+    #[test]
+    fn test_logical_select_with_labeled_drilldown() {
+        let syntax = logical_select("Entries".to_string(), "created_at".to_string());
+        let drilldown = LabeledDrilldown::new("tag".to_string()).keys(vec![("tag".to_string())]);
+        let expected =
+            LabeledDrilldownBuilder::new(DrilldownUsable::LogicalSelect(syntax.clone()),
+                                         drilldown.clone());
+        assert_eq!(expected.build(), (syntax + drilldown).build());
+    }
+
+    #[test]
+    fn test_logical_select_with_labeled_drilldown_sequence() {
+        let syntax = logical_select("Entries".to_string(), "created_at".to_string());
+        let drilldowns = vec![LabeledDrilldown::new("tag".to_string())
+                                  .keys(vec![("tag".to_string())]),
+                              LabeledDrilldown::new("author".to_string())
+                                  .keys(vec![("author".to_string())])];
+        let expected =
+            LabeledDrilldownSequenceBuilder::new(DrilldownUsable::LogicalSelect(syntax.clone()),
+                                                 drilldowns.clone());
+        assert_eq!(expected.build(), (syntax + drilldowns).build());
+    }
+
+    #[test]
+    fn test_logical_select_with_staged_columns() {
+        let syntax = logical_select("Entries".to_string(), "created_at".to_string());
+        let columns = StagedColumns::new("n_likes_double".to_string(),
+                                         StageType::Initial,
+                                         DataType::UInt32,
+                                         Value("n_likes * 2".to_string()));
+        let expected = StagedColumnsBuilder::new(DrilldownUsable::LogicalSelect(syntax.clone()),
+                                                 columns.clone())
+            .unwrap();
+        assert_eq!(expected.build(), (syntax + columns).unwrap().build());
+    }
 }
@@ -13,6 +13,7 @@ mod table_create_dsl;
 mod table_list_dsl;
 mod table_rename_dsl;
 mod table_remove_dsl;
+mod table_tokenize_dsl;
 mod column_copy_dsl;
 mod column_create_dsl;
 mod column_list_dsl;
@@ -36,6 +37,8 @@ mod truncate_dsl;
 #[cfg(feature="sharding")]
 mod logical_count_dsl;
 #[cfg(feature="sharding")]
+mod logical_range_filter_dsl;
+#[cfg(feature="sharding")]
 mod logical_select_dsl;
 #[cfg(feature="sharding")]
 mod logical_shard_list_dsl;
@@ -57,6 +60,7 @@ pub use dsl::table_create_dsl::table_create;
 pub use dsl::table_list_dsl::table_list;
 pub use dsl::table_rename_dsl::table_rename;
 pub use dsl::table_remove_dsl::table_remove;
+pub use dsl::table_tokenize_dsl::table_tokenize;
 pub use dsl::column_copy_dsl::column_copy;
 pub use dsl::column_create_dsl::column_create;
 pub use dsl::column_list_dsl::column_list;
@@ -80,6 +84,8 @@ pub use dsl::truncate_dsl::truncate;
 #[cfg(feature="sharding")]
 pub use dsl::logical_count_dsl::logical_count;
 #[cfg(feature="sharding")]
+pub use dsl::logical_range_filter_dsl::logical_range_filter;
+#[cfg(feature="sharding")]
 pub use dsl::logical_select_dsl::logical_select;
 #[cfg(feature="sharding")]
 pub use dsl::logical_shard_list_dsl::logical_shard_list;
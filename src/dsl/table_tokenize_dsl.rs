@@ -0,0 +1,18 @@
+use table_tokenize::TableTokenizeCommand;
+
+pub fn table_tokenize(table: String, string: String) -> TableTokenizeCommand {
+    TableTokenizeCommand::new(table, string)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use table_tokenize::TableTokenizeCommand;
+
+    #[test]
+    fn test_table_tokenize() {
+        let syntax = table_tokenize("Terms".to_string(), "element".to_string());
+        let actual = TableTokenizeCommand::new("Terms".to_string(), "element".to_string());
+        assert_eq!(syntax, actual);
+    }
+}
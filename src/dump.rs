@@ -88,6 +88,7 @@ impl Commandable for DumpCommand {
 extendable!(DumpCommand);
 request_cancellable!(DumpCommand);
 request_timeoutable!(DumpCommand);
+version_checkable!(DumpCommand);
 
 #[cfg(test)]
 mod test {
@@ -60,6 +60,7 @@ impl Commandable for ExtensionCommand {
 
 request_cancellable!(ExtensionCommand);
 request_timeoutable!(ExtensionCommand);
+version_checkable!(ExtensionCommand);
 
 #[cfg(test)]
 mod test {
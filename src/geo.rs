@@ -0,0 +1,221 @@
+use std::fmt;
+use types::data_type::DataType;
+use types::geo_point::GeoPoint;
+use types::geo_approximate_type::GeoApproximateType;
+use types::stage_type::StageType;
+use selectable::staged_columns::StagedColumns;
+use selectable::staged_columns::WindowableColumn::Value;
+
+/// `geo_in_circle` function expression.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::dsl::*;
+/// use ruroonga_command::geo::GeoInCircle;
+/// use ruroonga_command::types::geo_point::GeoPoint;
+/// let center = GeoPoint::from_degrees(35.681382, 139.766084);
+/// let circle = GeoInCircle::new("location".to_string(), center, 5000);
+/// let select = select("Shops".to_string()).filter(circle.to_string());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GeoInCircle {
+    column: String,
+    center: GeoPoint,
+    radius: i64,
+    approximate_type: Option<GeoApproximateType>,
+}
+
+impl GeoInCircle {
+    /// `radius` is specified in meters.
+    pub fn new(column: String, center: GeoPoint, radius: i64) -> GeoInCircle {
+        GeoInCircle {
+            column: column,
+            center: center,
+            radius: radius,
+            approximate_type: None,
+        }
+    }
+
+    pub fn approximate_type(mut self, approximate_type: GeoApproximateType) -> GeoInCircle {
+        self.approximate_type = Some(approximate_type);
+        self
+    }
+}
+
+impl fmt::Display for GeoInCircle {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.approximate_type {
+            Some(ref approximate) => {
+                write!(fmt,
+                       "geo_in_circle({}, \"{}\", {}, \"{}\")",
+                       self.column,
+                       self.center,
+                       self.radius,
+                       approximate)
+            }
+            None => {
+                write!(fmt,
+                       "geo_in_circle({}, \"{}\", {})",
+                       self.column,
+                       self.center,
+                       self.radius)
+            }
+        }
+    }
+}
+
+/// `geo_in_rectangle` function expression.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GeoInRectangle {
+    column: String,
+    top_left: GeoPoint,
+    bottom_right: GeoPoint,
+}
+
+impl GeoInRectangle {
+    pub fn new(column: String, top_left: GeoPoint, bottom_right: GeoPoint) -> GeoInRectangle {
+        GeoInRectangle {
+            column: column,
+            top_left: top_left,
+            bottom_right: bottom_right,
+        }
+    }
+}
+
+impl fmt::Display for GeoInRectangle {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt,
+               "geo_in_rectangle({}, \"{}\", \"{}\")",
+               self.column,
+               self.top_left,
+               self.bottom_right)
+    }
+}
+
+/// `geo_distance` function expression.
+///
+/// It can be used as an output column directly.
+/// To sort by distance, make a staged column with `staged_columns` and
+/// specify its label in `sort_keys`.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::dsl::*;
+/// use ruroonga_command::geo::GeoDistance;
+/// use ruroonga_command::types::geo_point::GeoPoint;
+/// let point = GeoPoint::from_degrees(35.681382, 139.766084);
+/// let distance = GeoDistance::new("location".to_string(), point);
+/// let select = select("Shops".to_string())
+///                  .sort_keys(vec![("distance".to_string())])
+///                  .output_columns(vec![("_key".to_string()), (distance.to_string())]);
+/// let _ = select + distance.staged_columns("distance".to_string());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GeoDistance {
+    column: String,
+    point: GeoPoint,
+    approximate_type: Option<GeoApproximateType>,
+}
+
+impl GeoDistance {
+    pub fn new(column: String, point: GeoPoint) -> GeoDistance {
+        GeoDistance {
+            column: column,
+            point: point,
+            approximate_type: None,
+        }
+    }
+
+    pub fn approximate_type(mut self, approximate_type: GeoApproximateType) -> GeoDistance {
+        self.approximate_type = Some(approximate_type);
+        self
+    }
+
+    /// Makes `filtered` stage column which holds distance in meters.
+    pub fn staged_columns(&self, label: String) -> StagedColumns {
+        StagedColumns::new(label,
+                           StageType::Filtered,
+                           DataType::Float,
+                           Value(format!("'{}'", self)))
+    }
+}
+
+impl fmt::Display for GeoDistance {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.approximate_type {
+            Some(ref approximate) => {
+                write!(fmt,
+                       "geo_distance({}, \"{}\", \"{}\")",
+                       self.column,
+                       self.point,
+                       approximate)
+            }
+            None => write!(fmt, "geo_distance({}, \"{}\")", self.column, self.point),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::geo_point::GeoPoint;
+    use types::geo_approximate_type::GeoApproximateType;
+    use select::SelectCommand;
+    use commandable::Commandable;
+
+    #[test]
+    fn test_geo_in_circle() {
+        let center = GeoPoint::from_milliseconds(128452975, 503157902);
+        let circle = GeoInCircle::new("location".to_string(), center, 5000);
+        assert_eq!("geo_in_circle(location, \"128452975x503157902\", 5000)".to_owned(),
+                   circle.to_string());
+        let approximated = circle.approximate_type(GeoApproximateType::Rectangle);
+        assert_eq!("geo_in_circle(location, \"128452975x503157902\", 5000, \"rectangle\")"
+                       .to_owned(),
+                   approximated.to_string());
+    }
+
+    #[test]
+    fn test_geo_in_rectangle() {
+        let rectangle = GeoInRectangle::new("location".to_string(),
+                                            GeoPoint::from_milliseconds(128693952, 502789785),
+                                            GeoPoint::from_milliseconds(128256370, 503359512));
+        assert_eq!("geo_in_rectangle(location, \"128693952x502789785\", \
+                    \"128256370x503359512\")"
+                       .to_owned(),
+                   rectangle.to_string());
+    }
+
+    #[test]
+    fn test_geo_distance() {
+        let point = GeoPoint::from_milliseconds(128452975, 503157902);
+        let distance = GeoDistance::new("location".to_string(), point)
+            .approximate_type(GeoApproximateType::Sphere);
+        assert_eq!("geo_distance(location, \"128452975x503157902\", \"sphere\")".to_owned(),
+                   distance.to_string());
+    }
+
+    #[test]
+    fn test_filter() {
+        let center = GeoPoint::from_milliseconds(128452975, 503157902);
+        let circle = GeoInCircle::new("location".to_string(), center, 5000);
+        let command = SelectCommand::new("Shops".to_string())
+            .filter(circle.to_string())
+            .to_command();
+        assert_eq!("select --table Shops --filter \'geo_in_circle(location, \
+                    \"128452975x503157902\", 5000)\'"
+                       .to_owned(),
+                   command);
+    }
+
+    #[test]
+    fn test_staged_columns() {
+        let point = GeoPoint::from_milliseconds(128452975, 503157902);
+        let distance = GeoDistance::new("location".to_string(), point);
+        let staged = distance.staged_columns("distance".to_string());
+        assert_eq!(vec!["geo_distance".to_string(), "location".to_string()],
+                   staged.references());
+    }
+}
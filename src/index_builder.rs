@@ -0,0 +1,223 @@
+use table_create::TableCreateCommand;
+use column_create::ColumnCreateCommand;
+use types::data_type::DataType;
+use types::column_type::ColumnType;
+use types::table_flag_type::TableFlagType;
+use types::column_flag_type::ColumnFlagType;
+use types::tokenizer_type::TokenizerType;
+use types::normalizer_type::NormalizerType;
+use types::token_filters_type::TokenFiltersType;
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum IndexBuilderError {
+    /// No source columns are specified.
+    EmptySources,
+}
+
+/// Full-text index builder.
+///
+/// It creates a lexicon table and an index column in it.
+/// `WithSection` flag is added when more than one source columns are specified.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::index_builder::IndexBuilder;
+/// use ruroonga_command::commandable::Commandable;
+/// let (lexicon, index) = IndexBuilder::new("Entries".to_string(),
+///                                          vec![("title".to_string()),
+///                                               ("content".to_string())])
+///                            .build()
+///                            .unwrap();
+/// println!("{}", lexicon.to_command());
+/// println!("{}", index.to_command());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct IndexBuilder {
+    table: String,
+    sources: Vec<String>,
+    lexicon: Option<String>,
+    name: Option<String>,
+    key_type: DataType,
+    tokenizer: TokenizerType,
+    normalizer: Option<NormalizerType>,
+    token_filters: Vec<TokenFiltersType>,
+    with_position: bool,
+}
+
+impl IndexBuilder {
+    /// `table` is the indexed table and `sources` are its columns.
+    pub fn new(table: String, sources: Vec<String>) -> IndexBuilder {
+        IndexBuilder {
+            table: table,
+            sources: sources,
+            lexicon: None,
+            name: None,
+            key_type: DataType::ShortText,
+            tokenizer: TokenizerType::Bigram,
+            normalizer: Some(NormalizerType::Auto),
+            token_filters: vec![],
+            with_position: true,
+        }
+    }
+
+    /// Lexicon table name. It is `Terms` prefixed with the indexed table by default.
+    pub fn lexicon(mut self, lexicon: String) -> IndexBuilder {
+        self.lexicon = Some(lexicon);
+        self
+    }
+
+    /// Index column name. It is joined names of the indexed table and sources by default.
+    pub fn name(mut self, name: String) -> IndexBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn key_type(mut self, key_type: DataType) -> IndexBuilder {
+        self.key_type = key_type;
+        self
+    }
+
+    pub fn tokenizer(mut self, tokenizer: TokenizerType) -> IndexBuilder {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn normalizer(mut self, normalizer: NormalizerType) -> IndexBuilder {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Disables normalization of the lexicon.
+    pub fn without_normalizer(mut self) -> IndexBuilder {
+        self.normalizer = None;
+        self
+    }
+
+    /// Adds a token filter of the lexicon.
+    pub fn token_filter(mut self, token_filter: TokenFiltersType) -> IndexBuilder {
+        self.token_filters.push(token_filter);
+        self
+    }
+
+    /// Token filters of the lexicon which are applied in order.
+    pub fn token_filters(mut self, token_filters: Vec<TokenFiltersType>) -> IndexBuilder {
+        self.token_filters = token_filters;
+        self
+    }
+
+    /// `WithPosition` flag is enabled by default for phrase search.
+    pub fn with_position(mut self, with_position: bool) -> IndexBuilder {
+        self.with_position = with_position;
+        self
+    }
+
+    /// Returns `table_create` for the lexicon and `column_create` for the index column.
+    pub fn build(self) -> Result<(TableCreateCommand, ColumnCreateCommand), IndexBuilderError> {
+        if self.sources.is_empty() {
+            return Err(IndexBuilderError::EmptySources);
+        }
+        let table = self.table;
+        let sources = self.sources;
+        let lexicon = self.lexicon.unwrap_or_else(|| format!("{}Terms", table));
+        let name = self.name
+            .unwrap_or_else(|| format!("{}_{}", table.to_lowercase(), sources.join("_")));
+        let mut table_create = TableCreateCommand::new(lexicon.clone())
+            .flags(vec![TableFlagType::PatKey])
+            .key_type(self.key_type)
+            .default_tokenizer(self.tokenizer);
+        if let Some(normalizer) = self.normalizer {
+            table_create = table_create.normalizer(normalizer);
+        }
+        if !self.token_filters.is_empty() {
+            table_create = table_create.token_filters(self.token_filters);
+        }
+        let mut flags = vec![ColumnFlagType::Index];
+        if sources.len() > 1 {
+            flags.push(ColumnFlagType::WithSection);
+        }
+        if self.with_position {
+            flags.push(ColumnFlagType::WithPosition);
+        }
+        let column_create = ColumnCreateCommand::new(lexicon, name)
+            .flags(flags)
+            .column_type(ColumnType::Reference(table))
+            .sources(sources);
+        Ok((table_create, column_create))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use table_create::TableCreateCommand;
+    use column_create::ColumnCreateCommand;
+    use types::data_type::DataType;
+    use types::column_type::ColumnType;
+    use types::table_flag_type::TableFlagType;
+    use types::column_flag_type::ColumnFlagType;
+    use types::tokenizer_type::TokenizerType;
+    use types::normalizer_type::NormalizerType;
+    use types::token_filters_type::TokenFiltersType;
+
+    #[test]
+    fn test_build() {
+        let actual = IndexBuilder::new("Entries".to_string(), vec![("title".to_string())])
+            .build()
+            .unwrap();
+        let lexicon = TableCreateCommand::new("EntriesTerms".to_string())
+            .flags(vec![TableFlagType::PatKey])
+            .key_type(DataType::ShortText)
+            .default_tokenizer(TokenizerType::Bigram)
+            .normalizer(NormalizerType::Auto);
+        let index = ColumnCreateCommand::new("EntriesTerms".to_string(),
+                                             "entries_title".to_string())
+            .flags(vec![ColumnFlagType::Index, ColumnFlagType::WithPosition])
+            .column_type(ColumnType::Reference("Entries".to_string()))
+            .sources(vec![("title".to_string())]);
+        assert_eq!((lexicon, index), actual);
+    }
+
+    #[test]
+    fn test_build_with_multiple_sources() {
+        let actual = IndexBuilder::new("Entries".to_string(),
+                                       vec![("title".to_string()), ("content".to_string())])
+            .lexicon("Terms".to_string())
+            .name("entries_index".to_string())
+            .tokenizer(TokenizerType::Mecab)
+            .without_normalizer()
+            .token_filter(TokenFiltersType::Stem)
+            .with_position(false)
+            .build()
+            .unwrap();
+        let lexicon = TableCreateCommand::new("Terms".to_string())
+            .flags(vec![TableFlagType::PatKey])
+            .key_type(DataType::ShortText)
+            .default_tokenizer(TokenizerType::Mecab)
+            .token_filter(TokenFiltersType::Stem);
+        let index = ColumnCreateCommand::new("Terms".to_string(), "entries_index".to_string())
+            .flags(vec![ColumnFlagType::Index, ColumnFlagType::WithSection])
+            .column_type(ColumnType::Reference("Entries".to_string()))
+            .sources(vec![("title".to_string()), ("content".to_string())]);
+        assert_eq!((lexicon, index), actual);
+    }
+
+    #[test]
+    fn test_build_with_token_filters() {
+        let (lexicon, _) = IndexBuilder::new("Entries".to_string(), vec![("title".to_string())])
+            .token_filter(TokenFiltersType::StopWord)
+            .token_filter(TokenFiltersType::Stem)
+            .build()
+            .unwrap();
+        let (_, query) = lexicon.build();
+        assert!(query.contains(&("token_filters".to_string(),
+                                 "TokenFilterStopWord,TokenFilterStem".to_string())));
+        assert!(!query.iter().any(|&(ref key, _)| key == "token_filter"));
+    }
+
+    #[test]
+    fn test_build_without_sources() {
+        let actual = IndexBuilder::new("Entries".to_string(), vec![]).build();
+        assert_eq!(Err(IndexBuilderError::EmptySources), actual);
+    }
+}
@@ -68,6 +68,7 @@ impl Commandable for IoFlushCommand {
 extendable!(IoFlushCommand);
 request_cancellable!(IoFlushCommand);
 request_timeoutable!(IoFlushCommand);
+version_checkable!(IoFlushCommand);
 
 #[cfg(test)]
 mod test {
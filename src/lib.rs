@@ -5,6 +5,7 @@
 //! generator. It reduces runtime errors about Groonga queries.
 
 extern crate url;
+extern crate serde_json;
 
 mod util;
 /// `queryable` provides a shortcut to create URI style Groonga query.
@@ -30,6 +31,11 @@ pub mod request_cancellable;
 /// `request_timeoutable` provides appending `timeout` functionality trait.
 #[macro_use]
 pub mod request_timeoutable;
+/// `version_checkable` provides a trait to check commands against a Groonga version.
+#[macro_use]
+pub mod version_checkable;
+/// `plugin_requirable` provides a trait to compute plugins which commands depend on.
+pub mod plugin_requirable;
 /// Provides types for Query builders.
 pub mod types;
 /// Type variant definitions for Groonga commands.
@@ -60,11 +66,16 @@ pub mod table_create;
 pub mod table_list;
 pub mod table_rename;
 pub mod table_remove;
+pub mod table_tokenize;
 pub mod column_copy;
 pub mod column_create;
 pub mod column_list;
 pub mod column_rename;
 pub mod column_remove;
+/// `index_builder` creates a lexicon table and a full-text index column at once.
+pub mod index_builder;
+/// `delete_batcher` previews records matched by a filter and deletes them in batches.
+pub mod delete_batcher;
 pub mod database_unmap;
 pub mod reindex;
 /// Using request cancel mechanism.
@@ -88,11 +99,20 @@ pub mod truncate;
 pub mod extension;
 pub mod schema;
 pub mod shutdown;
+/// `geo` provides geo search function expressions such as `geo_in_circle`.
+/// They are used in `filter`, `output_columns` and staged columns of `select`.
+pub mod geo;
+/// `response` provides typed Groonga command results which are decoded from JSON.
+pub mod response;
 #[cfg(feature="sharding")]
 /// `logical_count` provides `logical_count` command query builder.
 /// This module will enabled with `sharding` feature flag.
 pub mod logical_count;
 #[cfg(feature="sharding")]
+/// `logical_range_filter` provides `logical_range_filter` command query builder.
+/// This module will enabled with `sharding` feature flag.
+pub mod logical_range_filter;
+#[cfg(feature="sharding")]
 /// `logical_select` provides `logical_select` command query builder.
 /// This module will enabled with `sharding` feature flag.
 pub mod logical_select;
@@ -104,6 +124,10 @@ pub mod logical_shard_list;
 /// `logical_table_remove` provides `logical_table_remove` command query builder.
 /// This module will enabled with `sharding` feature flag.
 pub mod logical_table_remove;
+#[cfg(feature="sharding")]
+/// `shard` names shards of a logical table and creates a new shard.
+/// This module will enabled with `sharding` feature flag.
+pub mod shard;
 /// `dsl` provides its `XXXCommand` returning dsl syntax.
 ///
 /// This module is used like this:
@@ -6,6 +6,7 @@ use std::str::FromStr;
 use std::convert::AsRef;
 use self::InputType::{Json, ExtInputType};
 use util;
+use types::load_value::LoadValue;
 use queryable::LoadValues;
 use command_query::CommandQuery;
 use command_line::CommandLine;
@@ -16,6 +17,8 @@ use commandable::PostCommandable;
 use commandable::DataValues;
 use request_cancellable::RequestCancellable;
 use request_timeoutable::RequestTimeoutable;
+use version_checkable::{self, VersionCheckable, VersionCheckError};
+use types::groonga_version::GroongaVersion;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LoadCommand {
@@ -25,6 +28,9 @@ pub struct LoadCommand {
     values: LoadValues,
 }
 
+/// Pairs of a column name and its value.
+pub type LoadRecord = Vec<(String, LoadValue)>;
+
 #[derive (Debug)]
 pub enum InputTypeError {
     Empty,
@@ -89,6 +95,23 @@ impl LoadCommand {
         }
     }
 
+    /// Serializes `records` as JSON.
+    /// References are serialized as keys of the referred records.
+    pub fn with_records(table: String, records: Vec<LoadRecord>) -> LoadCommand {
+        let records: Vec<String> = records.iter()
+            .map(|record| {
+                let values: Vec<String> = record.iter()
+                    .map(|&(ref column, ref value)| {
+                        let column = LoadValue::Text(column.to_owned());
+                        format!("{}:{}", column.to_json(), value.to_json())
+                    })
+                    .collect();
+                format!("{{{}}}", values.join(","))
+            })
+            .collect();
+        LoadCommand::new(table, format!("[\n{}\n]", records.join(",\n")))
+    }
+
     pub fn columns(mut self, columns: Vec<String>) -> LoadCommand {
         let string = util::split_values_vec(columns);
         self.arguments.insert("columns".to_string(), string.to_owned());
@@ -146,6 +169,13 @@ impl PostCommandable for LoadCommand {
 request_cancellable!(LoadCommand);
 request_timeoutable!(LoadCommand);
 
+impl VersionCheckable for LoadCommand {
+    fn check_version(&self, version: &GroongaVersion) -> Result<(), VersionCheckError> {
+        let (command, query, _) = self.clone().build();
+        version_checkable::check(&command, &query, version)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -157,6 +187,7 @@ mod test {
     use queryable::PostQueryable;
     use commandable::Commandable;
     use commandable::PostCommandable;
+    use types::load_value::LoadValue;
 
     #[test]
     fn test_from_str() {
@@ -200,6 +231,19 @@ mod test {
         assert_eq!(expected, vanilla_load);
     }
 
+    #[test]
+    fn test_with_records() {
+        let records = vec![vec![("_key".to_string(), LoadValue::Text("alice".to_string())),
+                                ("friend".to_string(), LoadValue::Reference("bob".to_string()))],
+                           vec![("_key".to_string(), LoadValue::Text("bob".to_string())),
+                                ("tags".to_string(),
+                                 LoadValue::WeightedReferences(vec![("rust".to_string(), 2)]))]];
+        let load = LoadCommand::with_records("Users".to_string(), records);
+        let data = "[\n{\"_key\":\"alice\",\"friend\":\"bob\"},\n\
+                    {\"_key\":\"bob\",\"tags\":{\"rust\":2}}\n]";
+        assert_eq!(LoadCommand::new("Users".to_string(), data.to_string()), load);
+    }
+
     #[test]
     fn test_columns() {
         let load = LoadCommand::new("test".to_string(), DATA.to_string())
@@ -60,6 +60,7 @@ impl Commandable for LogLevelCommand {
 extendable!(LogLevelCommand);
 request_cancellable!(LogLevelCommand);
 request_timeoutable!(LogLevelCommand);
+version_checkable!(LogLevelCommand);
 
 #[cfg(test)]
 mod test {
@@ -67,6 +67,7 @@ impl Commandable for LogPutCommand {
 extendable!(LogPutCommand);
 request_cancellable!(LogPutCommand);
 request_timeoutable!(LogPutCommand);
+version_checkable!(LogPutCommand);
 
 #[cfg(test)]
 mod test {
@@ -55,6 +55,7 @@ impl Commandable for LogReopenCommand {
 
 request_cancellable!(LogReopenCommand);
 request_timeoutable!(LogReopenCommand);
+version_checkable!(LogReopenCommand);
 
 #[cfg(test)]
 mod test {
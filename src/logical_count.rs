@@ -1,6 +1,7 @@
 use command::{Command, Query};
 use command::Command::LogicalCount;
 use std::collections::HashMap;
+use util;
 use command_query::CommandQuery;
 use queryable::Queryable;
 use command_line::CommandLine;
@@ -9,6 +10,7 @@ use extendable::Extendable;
 use request_cancellable::RequestCancellable;
 use request_timeoutable::RequestTimeoutable;
 use types::range_filter_type::RangeFilterType;
+use types::time_range::TimeRange;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LogicalCountCommand {
@@ -44,6 +46,25 @@ impl LogicalCountCommand {
         self
     }
 
+    pub fn query(mut self, query: String) -> LogicalCountCommand {
+        let encoded = format!("'{}'", query);
+        self.arguments.insert("query".to_string(), encoded.to_owned());
+        self
+    }
+
+    pub fn match_columns(mut self, columns: Vec<String>) -> LogicalCountCommand {
+        let string = util::split_values_vec(columns);
+        self.arguments.insert("match_columns".to_string(), string.to_owned());
+        self
+    }
+
+    /// Filters records like `LogicalSelectCommand::post_filter`.
+    pub fn post_filter(mut self, filter: String) -> LogicalCountCommand {
+        let encoded = format!("'{}'", filter);
+        self.arguments.insert("post_filter".to_string(), encoded.to_owned());
+        self
+    }
+
     pub fn min(mut self, min: String) -> LogicalCountCommand {
         let encoded = format!("'{}'", min);
         self.arguments.insert("min".to_string(), encoded.to_owned());
@@ -68,6 +89,14 @@ impl LogicalCountCommand {
         self
     }
 
+    /// Sets `min`, `max` and their borders at once.
+    pub fn range(mut self, range: TimeRange) -> LogicalCountCommand {
+        for (key, value) in range.to_arguments() {
+            self.arguments.insert(key, value);
+        }
+        self
+    }
+
     pub fn build(self) -> (Command, Query) {
         let mut query: Query = vec![("logical_table".to_string(), self.logical_table),
                                     ("shard_key".to_string(), self.shard_key)];
@@ -97,6 +126,7 @@ impl Commandable for LogicalCountCommand {
 extendable!(LogicalCountCommand);
 request_cancellable!(LogicalCountCommand);
 request_timeoutable!(LogicalCountCommand);
+version_checkable!(LogicalCountCommand);
 
 #[cfg(test)]
 mod test {
@@ -108,6 +138,8 @@ mod test {
     use commandable::Commandable;
     use extendable::Extendable;
     use types::range_filter_type::RangeFilterType::{Exclude, Include};
+    use types::groonga_time::GroongaTime;
+    use types::time_range::TimeRange;
 
     #[test]
     fn test_new() {
@@ -138,6 +170,38 @@ mod test {
         assert_eq!(expected, select);
     }
 
+    #[test]
+    fn test_query() {
+        let select = LogicalCountCommand::new("Logs".to_string(), "timestamp".to_string())
+            .query("error".to_string())
+            .match_columns(vec!["message".to_string(), "host".to_string()]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("query".to_string(), "'error'".to_string());
+        arg.insert("match_columns".to_string(), "message,host".to_string());
+        let expected = LogicalCountCommand {
+            command: LogicalCount,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
+    #[test]
+    fn test_post_filter() {
+        let select = LogicalCountCommand::new("Logs".to_string(), "timestamp".to_string())
+            .post_filter("elapsed > 10".to_string());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("post_filter".to_string(), "'elapsed > 10'".to_string());
+        let expected = LogicalCountCommand {
+            command: LogicalCount,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
     #[test]
     fn test_min() {
         let select = LogicalCountCommand::new("Logs".to_string(), "timestamp".to_string())
@@ -202,6 +266,27 @@ mod test {
         assert_eq!(expected, select);
     }
 
+    #[test]
+    fn test_range() {
+        let range = TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+                                   GroongaTime::from_date(2016, 10, 19))
+            .max_border(Exclude);
+        let select = LogicalCountCommand::new("Logs".to_string(), "timestamp".to_string())
+            .range(range);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("min".to_string(), "'1476748800'".to_string());
+        arg.insert("min_border".to_string(), "'include'".to_string());
+        arg.insert("max".to_string(), "'1476835200'".to_string());
+        arg.insert("max_border".to_string(), "'exclude'".to_string());
+        let expected = LogicalCountCommand {
+            command: LogicalCount,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
     #[test]
     fn test_build() {
         let actual = LogicalCountCommand::new("Logs".to_string(), "timestamp".to_string()).build();
@@ -0,0 +1,314 @@
+use command::{Command, Query};
+use command::Command::LogicalRangeFilter;
+use std::collections::HashMap;
+use util;
+use command_query::CommandQuery;
+use queryable::Queryable;
+use command_line::CommandLine;
+use commandable::Commandable;
+use extendable::Extendable;
+use request_cancellable::RequestCancellable;
+use request_timeoutable::RequestTimeoutable;
+use types::order_type::OrderType;
+use types::range_filter_type::RangeFilterType;
+use types::time_range::TimeRange;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LogicalRangeFilterCommand {
+    command: Command,
+    logical_table: String,
+    shard_key: String,
+    arguments: HashMap<String, String>,
+}
+
+impl Default for LogicalRangeFilterCommand {
+    fn default() -> LogicalRangeFilterCommand {
+        LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "".to_string(),
+            shard_key: "".to_string(),
+            arguments: HashMap::new(),
+        }
+    }
+}
+
+impl LogicalRangeFilterCommand {
+    pub fn new(logical_table: String, shard_key: String) -> LogicalRangeFilterCommand {
+        LogicalRangeFilterCommand {
+            logical_table: logical_table,
+            shard_key: shard_key,
+            ..LogicalRangeFilterCommand::default()
+        }
+    }
+
+    pub fn min(mut self, min: String) -> LogicalRangeFilterCommand {
+        let encoded = format!("'{}'", min);
+        self.arguments.insert("min".to_string(), encoded.to_owned());
+        self
+    }
+
+    pub fn min_border(mut self, border: RangeFilterType) -> LogicalRangeFilterCommand {
+        let encoded = format!("'{}'", border);
+        self.arguments.insert("min_border".to_string(), encoded.to_owned());
+        self
+    }
+
+    pub fn max(mut self, max: String) -> LogicalRangeFilterCommand {
+        let encoded = format!("'{}'", max);
+        self.arguments.insert("max".to_string(), encoded.to_owned());
+        self
+    }
+
+    pub fn max_border(mut self, border: RangeFilterType) -> LogicalRangeFilterCommand {
+        let encoded = format!("'{}'", border);
+        self.arguments.insert("max_border".to_string(), encoded.to_owned());
+        self
+    }
+
+    /// Sets `min`, `max` and their borders at once.
+    pub fn range(mut self, range: TimeRange) -> LogicalRangeFilterCommand {
+        for (key, value) in range.to_arguments() {
+            self.arguments.insert(key, value);
+        }
+        self
+    }
+
+    /// Order of `shard_key`. Records are returned in ascending order by default.
+    pub fn order(mut self, order: OrderType) -> LogicalRangeFilterCommand {
+        self.arguments.insert("order".to_string(), format!("{}", order));
+        self
+    }
+
+    pub fn filter(mut self, filter: String) -> LogicalRangeFilterCommand {
+        let encoded = format!("'{}'", filter);
+        self.arguments.insert("filter".to_string(), encoded.to_owned());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> LogicalRangeFilterCommand {
+        let offset = format!("{}", offset);
+        self.arguments.insert("offset".to_string(), offset.to_owned());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> LogicalRangeFilterCommand {
+        let limit = format!("{}", limit);
+        self.arguments.insert("limit".to_string(), limit.to_owned());
+        self
+    }
+
+    pub fn output_columns(mut self, columns: Vec<String>) -> LogicalRangeFilterCommand {
+        let string = util::split_values_vec(columns);
+        self.arguments.insert("output_columns".to_string(), string.to_owned());
+        self
+    }
+
+    /// Forces to use or not to use range index.
+    /// Groonga decides it from the number of records when it is not specified.
+    pub fn use_range_index(mut self, use_range_index: bool) -> LogicalRangeFilterCommand {
+        let flag = if use_range_index { "yes" } else { "no" };
+        self.arguments.insert("use_range_index".to_string(), flag.to_string());
+        self
+    }
+
+    pub fn build(self) -> (Command, Query) {
+        let mut query: Query = vec![("logical_table".to_string(), self.logical_table),
+                                    ("shard_key".to_string(), self.shard_key)];
+        for (key, value) in &self.arguments {
+            query.push((key.to_owned(), value.to_owned()));
+        }
+        (LogicalRangeFilter, query)
+    }
+}
+
+impl Queryable for LogicalRangeFilterCommand {
+    fn to_query(self) -> String {
+        let (command, query) = self.build();
+        let mut command = CommandQuery::new(command, query);
+        command.encode()
+    }
+}
+
+impl Commandable for LogicalRangeFilterCommand {
+    fn to_command(self) -> String {
+        let (command, query) = self.build();
+        let mut command = CommandLine::new(command, query);
+        command.encode()
+    }
+}
+
+extendable!(LogicalRangeFilterCommand);
+request_cancellable!(LogicalRangeFilterCommand);
+request_timeoutable!(LogicalRangeFilterCommand);
+version_checkable!(LogicalRangeFilterCommand);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use command::Query;
+    use command::Command::LogicalRangeFilter;
+    use std::collections::HashMap;
+    use queryable::Queryable;
+    use commandable::Commandable;
+    use extendable::Extendable;
+    use types::order_type::OrderType;
+    use types::range_filter_type::RangeFilterType::{Exclude, Include};
+    use types::groonga_time::GroongaTime;
+    use types::time_range::TimeRange;
+
+    #[test]
+    fn test_new() {
+        let range_filter = LogicalRangeFilterCommand::new("Logs".to_string(),
+                                                          "timestamp".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: HashMap::new(),
+        };
+        assert_eq!(expected, range_filter);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let range_filter = LogicalRangeFilterCommand::new("Logs".to_string(),
+                                                          "timestamp".to_string())
+            .min("1476748800".to_string())
+            .min_border(Include)
+            .max("1476835200".to_string())
+            .max_border(Exclude);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("min".to_string(), "'1476748800'".to_string());
+        arg.insert("min_border".to_string(), "'include'".to_string());
+        arg.insert("max".to_string(), "'1476835200'".to_string());
+        arg.insert("max_border".to_string(), "'exclude'".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg.to_owned(),
+        };
+        assert_eq!(expected, range_filter);
+        let range = TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+                                   GroongaTime::from_date(2016, 10, 19))
+            .max_border(Exclude);
+        let ranged = LogicalRangeFilterCommand::new("Logs".to_string(), "timestamp".to_string())
+            .range(range);
+        assert_eq!(expected, ranged);
+    }
+
+    #[test]
+    fn test_order() {
+        let range_filter = LogicalRangeFilterCommand::new("Logs".to_string(),
+                                                          "timestamp".to_string())
+            .order(OrderType::Descending);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("order".to_string(), "descending".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, range_filter);
+    }
+
+    #[test]
+    fn test_filter() {
+        let range_filter = LogicalRangeFilterCommand::new("Logs".to_string(),
+                                                          "timestamp".to_string())
+            .filter("message @ \"error\"".to_string());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("filter".to_string(), "'message @ \"error\"'".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, range_filter);
+    }
+
+    #[test]
+    fn test_pagination() {
+        let range_filter = LogicalRangeFilterCommand::new("Logs".to_string(),
+                                                          "timestamp".to_string())
+            .offset(100)
+            .limit(50)
+            .output_columns(vec!["timestamp".to_string(), "message".to_string()]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("offset".to_string(), "100".to_string());
+        arg.insert("limit".to_string(), "50".to_string());
+        arg.insert("output_columns".to_string(), "timestamp,message".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, range_filter);
+    }
+
+    #[test]
+    fn test_use_range_index() {
+        let range_filter = LogicalRangeFilterCommand::new("Logs".to_string(),
+                                                          "timestamp".to_string())
+            .use_range_index(true);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("use_range_index".to_string(), "yes".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, range_filter);
+    }
+
+    #[test]
+    fn test_build() {
+        let actual = LogicalRangeFilterCommand::new("Logs".to_string(), "timestamp".to_string())
+            .build();
+        let expected_query: Query = vec![("logical_table".to_string(), "Logs".to_string()),
+                                         ("shard_key".to_string(), "timestamp".to_string())];
+        let expected = (LogicalRangeFilter, expected_query);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_queryable() {
+        let query = LogicalRangeFilterCommand::new("Logs".to_string(), "timestamp".to_string())
+            .order(OrderType::Descending)
+            .to_query();
+        let url_encoded = "/d/logical_range_filter?logical_table=Logs&shard_key=timestamp&\
+                           order=descending";
+        assert_eq!(url_encoded.to_string(), query);
+    }
+
+    #[test]
+    fn test_commandable() {
+        let query = LogicalRangeFilterCommand::new("Logs".to_string(), "timestamp".to_string())
+            .order(OrderType::Descending)
+            .to_command();
+        let cli_encoded = "logical_range_filter --logical_table Logs --shard_key timestamp \
+                           --order descending";
+        assert_eq!(cli_encoded.to_string(), query);
+    }
+
+    #[test]
+    fn test_extendable() {
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("user".to_string(), "defined".to_string());
+        let expected = LogicalRangeFilterCommand {
+            command: LogicalRangeFilter,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg.to_owned(),
+        };
+        let query = LogicalRangeFilterCommand::new("Logs".to_string(), "timestamp".to_string());
+        unsafe {
+            let extended = query.set_arguments(arg.to_owned());
+            assert_eq!(expected, extended);
+        }
+    }
+}
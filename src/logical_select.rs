@@ -17,15 +17,20 @@ use selectable::labeled_drilldownable::LabeledDrilldownable;
 use selectable::labeled_drilldown_builder::LabeledDrilldownBuilder;
 use selectable::labeled_drilldown_sequencable::LabeledDrilldownSequencable;
 use selectable::labeled_drilldown_sequence_builder::LabeledDrilldownSequenceBuilder;
-use selectable::staged_columns::StagedColumns;
+use selectable::labeled_drilldown_tree::{LabeledDrilldownTree, LabeledDrilldownTreeError};
+use selectable::staged_columns::{StagedColumns, StagedColumnsError};
 use selectable::staged_columns_builder::StagedColumnsBuilder;
 use selectable::staged_columnable::StagedColumnable;
+use selectable::staged_columns_sequence_builder::{StagedColumnsSequenceBuilder,
+                                                  StagedColumnsSequenceError};
+use selectable::staged_columns_sequencable::StagedColumnsSequencable;
 use std::ops::Add;
 use extendable::Extendable;
 use request_cancellable::RequestCancellable;
 use request_timeoutable::RequestTimeoutable;
 use selectable::drilldown_type::DrilldownUsable;
 use types::range_filter_type::RangeFilterType;
+use types::time_range::TimeRange;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LogicalSelectCommand {
@@ -85,13 +90,18 @@ impl LogicalSelectCommand {
         self
     }
 
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
+    /// Sets `min`, `max` and their borders at once.
+    pub fn range(mut self, range: TimeRange) -> LogicalSelectCommand {
+        for (key, value) in range.to_arguments() {
+            self.arguments.insert(key, value);
+        }
+        self
+    }
+
     pub fn query(mut self, query: String) -> LogicalSelectCommand {
-        // let encoded = format!("'{}'", query);
-        // self.arguments.insert("query".to_string(), encoded.to_owned());
-        // self
-        unimplemented!()
+        let encoded = format!("'{}'", query);
+        self.arguments.insert("query".to_string(), encoded.to_owned());
+        self
     }
 
     pub fn scorer(mut self, scorer: String) -> LogicalSelectCommand {
@@ -116,22 +126,16 @@ impl LogicalSelectCommand {
         self
     }
 
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
     pub fn adjuster(mut self, adjust_expr: String) -> LogicalSelectCommand {
-        // let encoded = format!("'{}'", adjust_expr);
-        // self.arguments.insert("adjuster".to_string(), encoded.to_owned());
-        // self
-        unimplemented!()
+        let encoded = format!("'{}'", adjust_expr);
+        self.arguments.insert("adjuster".to_string(), encoded.to_owned());
+        self
     }
 
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
     pub fn match_columns(mut self, columns: Vec<String>) -> LogicalSelectCommand {
-        // let string = util::split_values_vec(columns);
-        // self.arguments.insert("match_columns".to_string(), string.to_owned());
-        // self
-        unimplemented!()
+        let string = util::split_values_vec(columns);
+        self.arguments.insert("match_columns".to_string(), string.to_owned());
+        self
     }
 
     pub fn output_columns(mut self, columns: Vec<String>) -> LogicalSelectCommand {
@@ -152,45 +156,72 @@ impl LogicalSelectCommand {
         self
     }
 
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
+    /// Sets `sort_keys`, `offset` and `limit` at once.
+    ///
+    /// `offset` and `limit` are applied to records merged across shards only when
+    /// `sort_keys` is given. Otherwise they are applied in order of shards.
+    pub fn paginate(self, sort_keys: Vec<String>, offset: i64, limit: i64) -> LogicalSelectCommand {
+        self.sort_keys(sort_keys).offset(offset).limit(limit)
+    }
+
+    /// Filters records after `filtered` stage dynamic columns are calculated.
+    ///
+    /// It runs after `filter` and before `sort_keys`, so `post_filter` can refer to
+    /// `filtered` stage columns which `filter` can't.
+    pub fn post_filter(mut self, filter: String) -> LogicalSelectCommand {
+        let encoded = format!("'{}'", filter);
+        self.arguments.insert("post_filter".to_string(), encoded.to_owned());
+        self
+    }
+
+    /// Stores matched records into `table`. It is used with `load_columns` and `load_values`.
+    pub fn load_table(mut self, table: String) -> LogicalSelectCommand {
+        self.arguments.insert("load_table".to_string(), table);
+        self
+    }
+
+    pub fn load_columns(mut self, columns: Vec<String>) -> LogicalSelectCommand {
+        let string = util::split_values_vec(columns);
+        self.arguments.insert("load_columns".to_string(), string.to_owned());
+        self
+    }
+
+    pub fn load_values(mut self, values: Vec<String>) -> LogicalSelectCommand {
+        let string = util::split_values_vec(values);
+        self.arguments.insert("load_values".to_string(), string.to_owned());
+        self
+    }
+
+    /// Enables or disables match escalation regardless of `match_escalation_threshold`.
+    pub fn match_escalation(mut self, escalation: bool) -> LogicalSelectCommand {
+        let flag = if escalation { "yes" } else { "no" };
+        self.arguments.insert("match_escalation".to_string(), flag.to_string());
+        self
+    }
+
     pub fn cache(mut self, cache: bool) -> LogicalSelectCommand {
-        // let flag = if cache {
-        //     "yes"
-        // } else {
-        //     "no"
-        // };
-        // self.arguments.insert("cache".to_string(), flag.to_string());
-        // self
-        unimplemented!()
-    }
-
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
+        let flag = if cache { "yes" } else { "no" };
+        self.arguments.insert("cache".to_string(), flag.to_string());
+        self
+    }
+
     pub fn match_escalation_threshold(mut self, threshold: i64) -> LogicalSelectCommand {
-        // let string = format!("{}", threshold);
-        // self.arguments.insert("match_escalation_threshold".to_string(), string.to_owned());
-        // self
-        unimplemented!()
+        let string = format!("{}", threshold);
+        self.arguments.insert("match_escalation_threshold".to_string(), string.to_owned());
+        self
     }
 
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
     pub fn query_flags(mut self, flags: Vec<QueryFlagsType>) -> LogicalSelectCommand {
-        // let string = util::split_flags_vec(flags);
-        // self.arguments.insert("query_flags".to_string(), string.to_owned());
-        // self
-        unimplemented!()
+        let string = util::split_flags_vec(flags);
+        self.arguments.insert("query_flags".to_string(), string.to_owned());
+        self
     }
 
-    /// unimplemented!!
-    #[allow(unused_mut, unused_variables)]
     pub fn query_expander(mut self, synonym: (String, String)) -> LogicalSelectCommand {
-        // let (table, column) = synonym;
-        // let string = format!("{}.{}", table, column);
-        // self.arguments.insert("query_expander".to_string(), string.to_owned());
-        // self
-        unimplemented!()
+        let (table, column) = synonym;
+        let string = format!("{}.{}", table, column);
+        self.arguments.insert("query_expander".to_string(), string.to_owned());
+        self
     }
 
     pub fn build(self) -> (Command, Query) {
@@ -255,14 +286,35 @@ impl Add<Vec<LabeledDrilldown>> for LogicalSelectCommand {
     }
 }
 
+impl Add<LabeledDrilldownTree> for LogicalSelectCommand {
+    type Output = Result<LabeledDrilldownSequenceBuilder, LabeledDrilldownTreeError>;
+
+    fn add(self,
+           rhs: LabeledDrilldownTree)
+           -> Result<LabeledDrilldownSequenceBuilder, LabeledDrilldownTreeError> {
+        let drilldowns = try!(rhs.flatten());
+        Ok(LabeledDrilldownSequenceBuilder::new(DrilldownUsable::LogicalSelect(self), drilldowns))
+    }
+}
+
 impl Add<StagedColumns> for LogicalSelectCommand {
-    type Output = StagedColumnsBuilder;
+    type Output = Result<StagedColumnsBuilder, StagedColumnsError>;
 
-    fn add(self, rhs: StagedColumns) -> StagedColumnsBuilder {
+    fn add(self, rhs: StagedColumns) -> Result<StagedColumnsBuilder, StagedColumnsError> {
         StagedColumnsBuilder::new(DrilldownUsable::LogicalSelect(self), rhs)
     }
 }
 
+impl Add<Vec<StagedColumns>> for LogicalSelectCommand {
+    type Output = Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError>;
+
+    fn add(self,
+           rhs: Vec<StagedColumns>)
+           -> Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError> {
+        StagedColumnsSequenceBuilder::new(DrilldownUsable::LogicalSelect(self), rhs)
+    }
+}
+
 impl Drilldownable for LogicalSelectCommand {
     fn with_drilldown(self, rhs: Drilldown) -> DrilldownBuilder {
         DrilldownBuilder::new(DrilldownUsable::LogicalSelect(self), rhs)
@@ -285,14 +337,26 @@ impl LabeledDrilldownSequencable for LogicalSelectCommand {
 }
 
 impl StagedColumnable for LogicalSelectCommand {
-    fn with_staged_columns(self, rhs: StagedColumns) -> StagedColumnsBuilder {
+    fn with_staged_columns(self,
+                           rhs: StagedColumns)
+                           -> Result<StagedColumnsBuilder, StagedColumnsError> {
         StagedColumnsBuilder::new(DrilldownUsable::LogicalSelect(self), rhs)
     }
 }
 
+impl StagedColumnsSequencable for LogicalSelectCommand {
+    fn with_staged_columns_sequence
+        (self,
+         rhs: Vec<StagedColumns>)
+         -> Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError> {
+        StagedColumnsSequenceBuilder::new(DrilldownUsable::LogicalSelect(self), rhs)
+    }
+}
+
 extendable!(LogicalSelectCommand);
 request_cancellable!(LogicalSelectCommand);
 request_timeoutable!(LogicalSelectCommand);
+version_checkable!(LogicalSelectCommand);
 
 #[cfg(feature="sharding")]
 #[cfg(test)]
@@ -312,7 +376,13 @@ mod test {
     use selectable::labeled_drilldown_builder::LabeledDrilldownBuilder;
     use extendable::Extendable;
     use selectable::drilldown_type::DrilldownUsable;
+    use selectable::staged_columns::StagedColumns;
+    use selectable::staged_columns::WindowableColumn::Value;
+    use types::stage_type::StageType;
+    use types::data_type::DataType;
     use types::range_filter_type::RangeFilterType::{Include, Exclude};
+    use types::groonga_time::GroongaTime;
+    use types::time_range::TimeRange;
 
     #[test]
     fn test_new() {
@@ -408,7 +478,27 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    fn test_range() {
+        let range = TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+                                   GroongaTime::from_date(2016, 10, 19))
+            .max_border(Exclude);
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
+            .range(range);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("min".to_string(), "'1476748800'".to_string());
+        arg.insert("min_border".to_string(), "'include'".to_string());
+        arg.insert("max".to_string(), "'1476835200'".to_string());
+        arg.insert("max_border".to_string(), "'exclude'".to_string());
+        let expected = LogicalSelectCommand {
+            command: LogicalSelect,
+            logical_table: "Entries".to_string(),
+            shard_key: "created_at".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
+    #[test]
     fn test_query() {
         let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .query("_key:\"http://example.org/\"".to_string());
@@ -475,7 +565,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_adjuster() {
         let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .adjuster("content @ \"ruroonga\"".to_string());
@@ -492,7 +581,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_match_columns() {
         let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .match_columns(vec!["test".to_string(), "piyo".to_string()]);
@@ -553,7 +641,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_cache() {
         let select_yes = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .cache(true);
@@ -580,7 +667,72 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
+    fn test_paginate() {
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
+            .paginate(vec!["-created_at".to_string()], 20, 10);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("sort_keys".to_string(), "'-created_at'".to_string());
+        arg.insert("offset".to_string(), "20".to_string());
+        arg.insert("limit".to_string(), "10".to_string());
+        let expected = LogicalSelectCommand {
+            command: LogicalSelect,
+            logical_table: "Entries".to_string(),
+            shard_key: "created_at".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
+    #[test]
+    fn test_post_filter() {
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
+            .post_filter("n_likes_sum > 10".to_string());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("post_filter".to_string(), "'n_likes_sum > 10'".to_string());
+        let expected = LogicalSelectCommand {
+            command: LogicalSelect,
+            logical_table: "Entries".to_string(),
+            shard_key: "created_at".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
+    #[test]
+    fn test_load_table() {
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
+            .load_table("Summaries".to_string())
+            .load_columns(vec!["_key".to_string(), "n_likes".to_string()])
+            .load_values(vec!["tag".to_string(), "n_likes".to_string()]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("load_table".to_string(), "Summaries".to_string());
+        arg.insert("load_columns".to_string(), "_key,n_likes".to_string());
+        arg.insert("load_values".to_string(), "tag,n_likes".to_string());
+        let expected = LogicalSelectCommand {
+            command: LogicalSelect,
+            logical_table: "Entries".to_string(),
+            shard_key: "created_at".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
+    #[test]
+    fn test_match_escalation() {
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
+            .match_escalation(false);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("match_escalation".to_string(), "no".to_string());
+        let expected = LogicalSelectCommand {
+            command: LogicalSelect,
+            logical_table: "Entries".to_string(),
+            shard_key: "created_at".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
+    #[test]
     fn test_match_escalation_threshold() {
         let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .match_escalation_threshold(-1);
@@ -596,7 +748,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_query_flags() {
         let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .query_flags(vec![(QueryFlagsType::AllowColumn), (QueryFlagsType::AllowUpdate)]);
@@ -613,7 +764,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
     fn test_query_expander() {
         let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
             .query_expander(("Terms".to_string(), "synonym".to_string()));
@@ -714,6 +864,41 @@ mod test {
         assert_eq!(drilldownable, drilldown_builder);
     }
 
+    #[test]
+    fn test_labeled_drilldown_with_filter_and_columns() {
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string());
+        let columns = StagedColumns::new("n_likes_double".to_string(),
+                                         StageType::Initial,
+                                         DataType::UInt32,
+                                         Value("_nsubrecs * 2".to_string()));
+        let drilldown = LabeledDrilldown::new("tag".to_string())
+            .keys(vec![("tag".to_string())])
+            .filter("_nsubrecs > 1".to_string())
+            .columns(columns)
+            .unwrap();
+        let (command, query) = (select + drilldown).build();
+        assert_eq!(LogicalSelect, command);
+        assert!(query.contains(&("drilldowns[tag].filter".to_string(),
+                                 "'_nsubrecs > 1'".to_string())));
+        assert!(query.contains(&("drilldowns[tag].columns[n_likes_double].stage".to_string(),
+                                 "initial".to_string())));
+    }
+
+    #[test]
+    fn test_staged_columns_with_post_filter() {
+        let select = LogicalSelectCommand::new("Entries".to_string(), "created_at".to_string())
+            .post_filter("n_likes_double > 10".to_string());
+        let columns = StagedColumns::new("n_likes_double".to_string(),
+                                         StageType::Filtered,
+                                         DataType::UInt32,
+                                         Value("n_likes * 2".to_string()));
+        let (command, query) = (select + columns).unwrap().build();
+        assert_eq!(LogicalSelect, command);
+        assert!(query.contains(&("post_filter".to_string(), "'n_likes_double > 10'".to_string())));
+        assert!(query.contains(&("columns[n_likes_double].stage".to_string(),
+                                 "filtered".to_string())));
+    }
+
     #[test]
     fn test_extendable() {
         let mut arg: HashMap<String, String> = HashMap::new();
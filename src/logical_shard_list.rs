@@ -62,6 +62,7 @@ impl Commandable for LogicalShardListCommand {
 extendable!(LogicalShardListCommand);
 request_cancellable!(LogicalShardListCommand);
 request_timeoutable!(LogicalShardListCommand);
+version_checkable!(LogicalShardListCommand);
 
 #[cfg(test)]
 mod test {
@@ -9,6 +9,7 @@ use extendable::Extendable;
 use request_cancellable::RequestCancellable;
 use request_timeoutable::RequestTimeoutable;
 use types::range_filter_type::RangeFilterType;
+use types::time_range::TimeRange;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LogicalTableRemoveCommand {
@@ -62,6 +63,14 @@ impl LogicalTableRemoveCommand {
         self
     }
 
+    /// Sets `min`, `max` and their borders at once.
+    pub fn range(mut self, range: TimeRange) -> LogicalTableRemoveCommand {
+        for (key, value) in range.to_arguments() {
+            self.arguments.insert(key, value);
+        }
+        self
+    }
+
     pub fn dependent(mut self, dependent: bool) -> LogicalTableRemoveCommand {
         let flag = if dependent { "yes" } else { "no" };
         self.arguments.insert("dependent".to_string(), flag.to_string());
@@ -98,6 +107,7 @@ impl Commandable for LogicalTableRemoveCommand {
 extendable!(LogicalTableRemoveCommand);
 request_cancellable!(LogicalTableRemoveCommand);
 request_timeoutable!(LogicalTableRemoveCommand);
+version_checkable!(LogicalTableRemoveCommand);
 
 #[cfg(test)]
 mod test {
@@ -109,6 +119,8 @@ mod test {
     use commandable::Commandable;
     use extendable::Extendable;
     use types::range_filter_type::RangeFilterType::{Include, Exclude};
+    use types::groonga_time::GroongaTime;
+    use types::time_range::TimeRange;
 
     #[test]
     fn test_new() {
@@ -187,6 +199,27 @@ mod test {
         assert_eq!(expected, select);
     }
 
+    #[test]
+    fn test_range() {
+        let range = TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+                                   GroongaTime::from_date(2016, 10, 19))
+            .max_border(Exclude);
+        let select = LogicalTableRemoveCommand::new("Logs".to_string(), "timestamp".to_string())
+            .range(range);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("min".to_string(), "'1476748800'".to_string());
+        arg.insert("min_border".to_string(), "'include'".to_string());
+        arg.insert("max".to_string(), "'1476835200'".to_string());
+        arg.insert("max_border".to_string(), "'exclude'".to_string());
+        let expected = LogicalTableRemoveCommand {
+            command: LogicalTableRemove,
+            logical_table: "Logs".to_string(),
+            shard_key: "timestamp".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, select);
+    }
+
     #[test]
     fn test_dependent() {
         let dependent_yes = LogicalTableRemoveCommand::new("Logs".to_string(),
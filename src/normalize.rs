@@ -138,6 +138,7 @@ impl Commandable for NormalizeCommand {
 
 request_cancellable!(NormalizeCommand);
 request_timeoutable!(NormalizeCommand);
+version_checkable!(NormalizeCommand);
 
 #[cfg(test)]
 mod test {
@@ -57,6 +57,7 @@ impl Commandable for NormalizerListCommand {
 extendable!(NormalizerListCommand);
 request_cancellable!(NormalizerListCommand);
 request_timeoutable!(NormalizerListCommand);
+version_checkable!(NormalizerListCommand);
 
 #[cfg(test)]
 mod test {
@@ -59,6 +59,7 @@ impl Commandable for ObjectExistCommand {
 extendable!(ObjectExistCommand);
 request_cancellable!(ObjectExistCommand);
 request_timeoutable!(ObjectExistCommand);
+version_checkable!(ObjectExistCommand);
 
 #[cfg(test)]
 mod test {
@@ -62,6 +62,7 @@ impl Commandable for ObjectInspectCommand {
 extendable!(ObjectInspectCommand);
 request_cancellable!(ObjectInspectCommand);
 request_timeoutable!(ObjectInspectCommand);
+version_checkable!(ObjectInspectCommand);
 
 #[cfg(test)]
 mod test {
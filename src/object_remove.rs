@@ -65,6 +65,7 @@ impl Commandable for ObjectRemoveCommand {
 extendable!(ObjectRemoveCommand);
 request_cancellable!(ObjectRemoveCommand);
 request_timeoutable!(ObjectRemoveCommand);
+version_checkable!(ObjectRemoveCommand);
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,66 @@
+use types::plugin::Plugin;
+use plugin_register::PluginRegisterCommand;
+use table_create::TableCreateCommand;
+use column_create::ColumnCreateCommand;
+
+pub trait PluginRequirable {
+    /// Returns plugins which must be registered before running this command.
+    fn required_plugins(&self) -> Vec<Plugin>;
+}
+
+/// Computes `plugin_register` commands which must run before `tables` and `columns`.
+///
+/// Each plugin is registered only once in the order of its first appearance.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::dsl::*;
+/// use ruroonga_command::plugin_requirable::plugin_registers;
+/// use ruroonga_command::types::token_filters_type::TokenFiltersType;
+/// let terms = table_create("Terms".to_string())
+///                 .token_filter(TokenFiltersType::Stem);
+/// let registers = plugin_registers(&[terms], &[]);
+/// assert_eq!(1, registers.len());
+/// ```
+pub fn plugin_registers(tables: &[TableCreateCommand],
+                        columns: &[ColumnCreateCommand])
+                        -> Vec<PluginRegisterCommand> {
+    let required = tables.iter()
+        .flat_map(|t| t.required_plugins())
+        .chain(columns.iter().flat_map(|c| c.required_plugins()));
+    let mut plugins: Vec<Plugin> = vec![];
+    for plugin in required {
+        if !plugins.contains(&plugin) {
+            plugins.push(plugin);
+        }
+    }
+    plugins.into_iter().map(PluginRegisterCommand::from_plugin).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::plugin::Plugin;
+    use types::tokenizer_type::TokenizerType;
+    use types::token_filters_type::TokenFiltersType;
+    use types::data_type::DataType;
+    use table_create::TableCreateCommand;
+    use column_create::ColumnCreateCommand;
+    use plugin_register::PluginRegisterCommand;
+
+    #[test]
+    fn test_plugin_registers() {
+        let terms = TableCreateCommand::new("Terms".to_string())
+            .default_tokenizer(TokenizerType::Mecab)
+            .token_filter(TokenFiltersType::Stem);
+        let words = TableCreateCommand::new("Words".to_string())
+            .token_filter(TokenFiltersType::Stem);
+        let column = ColumnCreateCommand::new("Entries".to_string(), "title".to_string())
+            .column_type(DataType::ShortText);
+        let registers = plugin_registers(&[terms, words], &[column]);
+        let expected = vec![PluginRegisterCommand::from_plugin(Plugin::TokenizersMecab),
+                            PluginRegisterCommand::from_plugin(Plugin::TokenFiltersStem)];
+        assert_eq!(expected, registers);
+    }
+}
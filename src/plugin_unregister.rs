@@ -1,6 +1,7 @@
 use command::{Command, Query};
 use command::Command::PluginUnregister;
 use std::collections::HashMap;
+use types::plugin::Plugin;
 use command_query::CommandQuery;
 use queryable::Queryable;
 use command_line::CommandLine;
@@ -31,6 +32,10 @@ impl PluginUnregisterCommand {
         PluginUnregisterCommand { name: name, ..PluginUnregisterCommand::default() }
     }
 
+    pub fn from_plugin(plugin: Plugin) -> PluginUnregisterCommand {
+        PluginUnregisterCommand::new(plugin.to_string())
+    }
+
     pub fn build(self) -> (Command, Query) {
         let mut query: Query = vec![("name".to_string(), self.name)];
         for (key, value) in &self.arguments {
@@ -59,6 +64,7 @@ impl Commandable for PluginUnregisterCommand {
 extendable!(PluginUnregisterCommand);
 request_cancellable!(PluginUnregisterCommand);
 request_timeoutable!(PluginUnregisterCommand);
+version_checkable!(PluginUnregisterCommand);
 
 #[cfg(test)]
 mod test {
@@ -69,6 +75,7 @@ mod test {
     use queryable::Queryable;
     use commandable::Commandable;
     use extendable::Extendable;
+    use types::plugin::Plugin;
 
     #[test]
     fn test_new() {
@@ -81,6 +88,17 @@ mod test {
         assert_eq!(expected, plugin_unregister);
     }
 
+    #[test]
+    fn test_from_plugin() {
+        let plugin_unregister = PluginUnregisterCommand::from_plugin(Plugin::TokenFiltersStem);
+        let expected = PluginUnregisterCommand {
+            command: PluginUnregister,
+            name: "token_filters/stem".to_string(),
+            arguments: HashMap::new(),
+        };
+        assert_eq!(expected, plugin_unregister);
+    }
+
     #[test]
     fn test_build() {
         let actual = PluginUnregisterCommand::new("test_plugin".to_string()).build();
@@ -59,6 +59,7 @@ impl Commandable for ReindexCommand {
 extendable!(ReindexCommand);
 request_cancellable!(ReindexCommand);
 request_timeoutable!(ReindexCommand);
+version_checkable!(ReindexCommand);
 
 #[cfg(test)]
 mod test {
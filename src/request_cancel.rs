@@ -57,6 +57,7 @@ impl Commandable for RequestCancelCommand {
 
 request_cancellable!(RequestCancelCommand);
 request_timeoutable!(RequestCancelCommand);
+version_checkable!(RequestCancelCommand);
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,122 @@
+use std::str::FromStr;
+use serde_json::Value;
+use types::column_flag_type::ColumnFlagType;
+use types::data_type::DataType;
+use response::{self, ResponseError};
+
+/// A column which is returned from `column_list`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Column {
+    pub id: u64,
+    pub name: String,
+    pub path: String,
+    /// Storage type such as `fix`, `var` or `index`.
+    pub column_type: String,
+    pub flags: Vec<ColumnFlagType>,
+    /// The table which has this column.
+    pub domain: String,
+    /// Value type of this column. It is `ExtDataType` when it refers a table.
+    pub range: Option<DataType>,
+    pub sources: Vec<String>,
+}
+
+/// Typed `column_list` result.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::column_list::ColumnListResponse;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [[["id", "UInt32"], ["name", "ShortText"], ["path", "ShortText"],
+///                  ["type", "ShortText"], ["flags", "ShortText"], ["domain", "ShortText"],
+///                  ["range", "ShortText"], ["source", "ShortText"]],
+///                 [257, "title", "/tmp/db.0000101", "var", "COLUMN_SCALAR|PERSISTENT",
+///                  "Entries", "ShortText", []]]]"#;
+/// let response: ColumnListResponse = json.parse().unwrap();
+/// assert_eq!("title", response.columns[0].name);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ColumnListResponse {
+    pub columns: Vec<Column>,
+}
+
+fn sources(value: Option<&Value>) -> Vec<String> {
+    match value.and_then(|v| v.as_array()) {
+        Some(sources) => sources.iter().filter_map(|s| s.as_str().map(|s| s.to_owned())).collect(),
+        None => vec![],
+    }
+}
+
+impl FromStr for ColumnListResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<ColumnListResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let mut columns = vec![];
+        for record in try!(response::records(&body)) {
+            let flags = try!(response::get_str(&record, "flags"));
+            let range = try!(response::get_str(&record, "range"));
+            columns.push(Column {
+                id: try!(response::get_u64(&record, "id")),
+                name: try!(response::get_str(&record, "name")),
+                path: try!(response::get_str(&record, "path")),
+                column_type: try!(response::get_str(&record, "type")),
+                flags: response::split_flags(&flags)
+                    .into_iter()
+                    .filter_map(|f| ColumnFlagType::from_str(f).ok())
+                    .collect(),
+                domain: try!(response::get_str(&record, "domain")),
+                range: DataType::from_str(&range).ok(),
+                sources: sources(record.get("source")),
+            });
+        }
+        Ok(ColumnListResponse { columns: columns })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::column_flag_type::ColumnFlagType;
+    use types::data_type::DataType;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [[["id", "UInt32"], ["name", "ShortText"], ["path", "ShortText"],
+                         ["type", "ShortText"], ["flags", "ShortText"],
+                         ["domain", "ShortText"], ["range", "ShortText"],
+                         ["source", "ShortText"]],
+                        [256, "_key", "", "", "COLUMN_SCALAR", "Entries", "ShortText", []],
+                        [259, "index", "/tmp/db.0000103", "index",
+                         "COLUMN_INDEX|WITH_SECTION|PERSISTENT", "Terms", "Entries",
+                         ["title", "content"]]]]"#;
+        let response: ColumnListResponse = json.parse().unwrap();
+        assert_eq!(2, response.columns.len());
+        let key = &response.columns[0];
+        assert_eq!(vec![ColumnFlagType::Scalar], key.flags);
+        assert_eq!(Some(DataType::ShortText), key.range);
+        let expected = Column {
+            id: 259,
+            name: "index".to_owned(),
+            path: "/tmp/db.0000103".to_owned(),
+            column_type: "index".to_owned(),
+            flags: vec![ColumnFlagType::Index,
+                        ColumnFlagType::WithSection,
+                        ColumnFlagType::ExtColumnFlagType("PERSISTENT".to_owned())],
+            domain: "Terms".to_owned(),
+            range: Some(DataType::ExtDataType("Entries".to_owned())),
+            sources: vec!["title".to_owned(), "content".to_owned()],
+        };
+        assert_eq!(expected, response.columns[1]);
+    }
+
+    #[test]
+    fn test_from_str_with_error() {
+        let json = "[[-22, 1337566253.89858, 0.000355720520019531, \"table doesn't exist\"]]";
+        let response: Result<ColumnListResponse, _> = json.parse();
+        if let Err(ResponseError::Groonga(-22, _)) = response {
+        } else {
+            panic!("An error response must be decoded as an error!")
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::str::FromStr;
+use shard::Shard;
+use response::{self, ResponseError};
+
+/// Typed `logical_shard_list` result.
+///
+/// `FromStr` reads shard names as UTC dates.
+/// Use `parse_with_utc_offset` when the server names shards in another time zone.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::logical_shard_list::LogicalShardListResponse;
+/// use ruroonga_command::shard::ShardRetention;
+/// use ruroonga_command::types::groonga_time::GroongaTime;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [{"name": "Logs_20161017"}, {"name": "Logs_20161018"}]]"#;
+/// let response: LogicalShardListResponse = json.parse().unwrap();
+/// let retention = ShardRetention::new(GroongaTime::from_date(2016, 10, 18));
+/// let removes = retention.table_removes(&response.shards);
+/// assert_eq!(1, removes.len());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LogicalShardListResponse {
+    pub shards: Vec<Shard>,
+}
+
+impl LogicalShardListResponse {
+    /// Parses shard names as dates in the local time of `utc_offset` seconds.
+    ///
+    /// See `Shard::parse_with_utc_offset`.
+    pub fn parse_with_utc_offset(s: &str,
+                                 utc_offset: i64)
+                                 -> Result<LogicalShardListResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let mut shards = vec![];
+        for name in try!(response::names(&body)) {
+            let shard = try!(Shard::parse_with_utc_offset(&name, utc_offset)
+                .ok_or_else(|| ResponseError::Malformed(format!("invalid shard name: {}", name))));
+            shards.push(shard);
+        }
+        Ok(LogicalShardListResponse { shards: shards })
+    }
+}
+
+impl FromStr for LogicalShardListResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<LogicalShardListResponse, ResponseError> {
+        LogicalShardListResponse::parse_with_utc_offset(s, 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use shard::{ShardGranularity, ShardRetention};
+    use logical_table_remove::LogicalTableRemoveCommand;
+    use types::groonga_time::GroongaTime;
+    use types::time_range::TimeRange;
+    use types::range_filter_type::RangeFilterType;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [{"name": "Logs_20150203"}, {"name": "Logs_201503"}]]"#;
+        let response: LogicalShardListResponse = json.parse().unwrap();
+        assert_eq!(2, response.shards.len());
+        assert_eq!("Logs_20150203".to_owned(), response.shards[0].name);
+        assert_eq!(GroongaTime::from_date(2015, 2, 3), response.shards[0].start);
+        assert_eq!(ShardGranularity::Month, response.shards[1].granularity);
+    }
+
+    #[test]
+    fn test_parse_with_utc_offset() {
+        let json = r#"[{"name": "Logs_20161017"}, {"name": "Logs_20161018"}]"#;
+        let response = LogicalShardListResponse::parse_with_utc_offset(json, 9 * 3600).unwrap();
+        assert_eq!(GroongaTime::from_datetime(2016, 10, 16, 15, 0, 0),
+                   response.shards[0].start);
+        let retention = ShardRetention::new(GroongaTime::from_datetime(2016, 10, 17, 15, 0, 0));
+        let range = TimeRange::new(GroongaTime::from_datetime(2016, 10, 16, 15, 0, 0),
+                                   GroongaTime::from_datetime(2016, 10, 17, 15, 0, 0))
+            .max_border(RangeFilterType::Exclude);
+        assert_eq!(vec![LogicalTableRemoveCommand::new("Logs".to_string(),
+                                                       "timestamp".to_string())
+                            .range(range)],
+                   retention.logical_table_removes("timestamp".to_string(), &response.shards));
+    }
+
+    #[test]
+    fn test_from_str_with_invalid_name() {
+        let json = r#"[{"name": "Logs"}]"#;
+        assert!(json.parse::<LogicalShardListResponse>().is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Provides typed representations of Groonga command results.
+//!
+//! Each response type implements `FromStr` against JSON output.
+//! Both a whole response which has a header and only its body are accepted.
+
+pub mod column_list;
+#[cfg(feature="sharding")]
+pub mod logical_shard_list;
+pub mod normalize;
+pub mod normalizer_list;
+pub mod object_inspect;
+pub mod select;
+pub mod status;
+pub mod table_list;
+pub mod tokenize;
+pub mod tokenizer_list;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::error;
+use serde_json;
+use serde_json::Value;
+use std::str::FromStr;
+use types::char_type::CharType;
+
+#[derive (Debug)]
+pub enum ResponseError {
+    /// The response is not a valid JSON.
+    Json(serde_json::Error),
+    /// Groonga returns an error. It holds return code and error message.
+    Groonga(i64, String),
+    /// The response does not have an expected structure.
+    Malformed(String),
+}
+
+impl fmt::Display for ResponseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResponseError::Json(ref e) => write!(fmt, "invalid JSON: {}", e),
+            ResponseError::Groonga(rc, ref message) => {
+                write!(fmt, "Groonga error ({}): {}", rc, message)
+            }
+            ResponseError::Malformed(ref message) => write!(fmt, "malformed response: {}", message),
+        }
+    }
+}
+
+impl error::Error for ResponseError {
+    fn description(&self) -> &str {
+        match *self {
+            ResponseError::Json(_) => "invalid JSON",
+            ResponseError::Groonga(_, _) => "Groonga error",
+            ResponseError::Malformed(_) => "malformed response",
+        }
+    }
+}
+
+impl From<serde_json::Error> for ResponseError {
+    fn from(e: serde_json::Error) -> ResponseError {
+        ResponseError::Json(e)
+    }
+}
+
+type Record = HashMap<String, Value>;
+
+// Header is `[return_code, start_time, elapsed_time, (error_message, ...)]`.
+fn is_header(value: &Value) -> bool {
+    match value.as_array() {
+        Some(header) => {
+            header.len() >= 3 && header[0].is_i64() && header[1].is_number() &&
+            header[2].is_number()
+        }
+        None => false,
+    }
+}
+
+/// Extracts body from Groonga response.
+/// Returns `ResponseError::Groonga` when return code is not zero.
+pub fn body(json: &str) -> Result<Value, ResponseError> {
+    let value: Value = try!(serde_json::from_str(json));
+    if let Some(object) = value.as_object() {
+        if let (Some(header), Some(body)) = (object.get("header"), object.get("body")) {
+            let rc = header.get("return_code").and_then(|rc| rc.as_i64()).unwrap_or(0);
+            if rc != 0 {
+                let message = header.get("error")
+                    .and_then(|e| e.get("message"))
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("");
+                return Err(ResponseError::Groonga(rc, message.to_owned()));
+            }
+            return Ok(body.clone());
+        }
+    }
+    if let Some(array) = value.as_array() {
+        if !array.is_empty() && array.len() <= 2 && is_header(&array[0]) {
+            let header = array[0].as_array().unwrap();
+            let rc = header[0].as_i64().unwrap_or(0);
+            if rc != 0 {
+                let message = header.get(3).and_then(|m| m.as_str()).unwrap_or("");
+                return Err(ResponseError::Groonga(rc, message.to_owned()));
+            }
+            return array.get(1)
+                .cloned()
+                .ok_or_else(|| ResponseError::Malformed("body is missing".to_owned()));
+        }
+    }
+    Ok(value)
+}
+
+/// Converts `[[[name, type], ...], [value, ...], ...]` style body into records.
+fn records(body: &Value) -> Result<Vec<Record>, ResponseError> {
+    let rows = try!(body.as_array()
+        .ok_or_else(|| ResponseError::Malformed("records must be an array".to_owned())));
+    let names: Vec<String> = match rows.first().and_then(|h| h.as_array()) {
+        Some(header) => {
+            header.iter()
+                .map(|column| match column.as_array().and_then(|c| c.first()) {
+                    Some(name) => name.as_str().unwrap_or("").to_owned(),
+                    None => column.as_str().unwrap_or("").to_owned(),
+                })
+                .collect()
+        }
+        None => return Ok(vec![]),
+    };
+    let mut records = vec![];
+    for row in rows.iter().skip(1) {
+        let values = try!(row.as_array()
+            .ok_or_else(|| ResponseError::Malformed("record must be an array".to_owned())));
+        let mut record = Record::new();
+        for (name, value) in names.iter().zip(values.iter()) {
+            record.insert(name.to_owned(), value.clone());
+        }
+        records.push(record);
+    }
+    Ok(records)
+}
+
+fn get_u64(record: &Record, name: &str) -> Result<u64, ResponseError> {
+    record.get(name)
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| ResponseError::Malformed(format!("`{}` must be an unsigned integer", name)))
+}
+
+fn get_str(record: &Record, name: &str) -> Result<String, ResponseError> {
+    match record.get(name) {
+        Some(&Value::Null) => Ok("".to_owned()),
+        Some(&Value::String(ref s)) => Ok(s.to_owned()),
+        _ => Err(ResponseError::Malformed(format!("`{}` must be a string", name))),
+    }
+}
+
+/// Extracts names from `[{"name": name}, ...]` style body.
+fn names(body: &Value) -> Result<Vec<String>, ResponseError> {
+    let objects = try!(body.as_array()
+        .ok_or_else(|| ResponseError::Malformed("body must be an array".to_owned())));
+    let mut names = vec![];
+    for object in objects {
+        let name = try!(object.get("name")
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| ResponseError::Malformed("`name` must be a string".to_owned())));
+        names.push(name.to_owned());
+    }
+    Ok(names)
+}
+
+/// Extracts character types and blank markers from `WITH_TYPES` output.
+///
+/// A type is formatted as `alpha` or `alpha|blank`.
+fn char_types(value: Option<&Value>) -> (Vec<CharType>, Vec<bool>) {
+    let mut types = vec![];
+    let mut blanks = vec![];
+    let values = match value.and_then(|v| v.as_array()) {
+        Some(values) => values.iter().filter_map(|v| v.as_str()).collect(),
+        None => vec![],
+    };
+    for char_type in values {
+        let mut parts = char_type.split('|');
+        types.push(parts.next()
+            .and_then(|t| CharType::from_str(t).ok())
+            .unwrap_or(CharType::Null));
+        blanks.push(parts.any(|p| p == "blank"));
+    }
+    (types, blanks)
+}
+
+/// Splits `FLAG1|FLAG2` style value.
+fn split_flags(flags: &str) -> Vec<&str> {
+    flags.split('|').filter(|f| !f.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_body_with_header() {
+        let json = "[[0, 1337566253.89858, 0.000355720520019531], [1, 2]]";
+        assert_eq!("[1,2]".to_owned(), body(json).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_body_with_command_version_3_header() {
+        let json = "{\"header\": {\"return_code\": 0, \"start_time\": 0.0, \
+                    \"elapsed_time\": 0.0}, \"body\": true}";
+        assert_eq!("true".to_owned(), body(json).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_body_without_header() {
+        assert_eq!("[[1,2]]".to_owned(), body("[[1, 2]]").unwrap().to_string());
+    }
+
+    #[test]
+    fn test_body_with_error() {
+        let json = "[[-22, 1337566253.89858, 0.000355720520019531, \"invalid table\"]]";
+        match body(json) {
+            Err(ResponseError::Groonga(rc, message)) => {
+                assert_eq!(-22, rc);
+                assert_eq!("invalid table".to_owned(), message);
+            }
+            _ => panic!("An error response must be decoded as an error!"),
+        }
+    }
+
+    #[test]
+    fn test_records() {
+        let value = body("[[[\"id\", \"UInt32\"], [\"name\", \"ShortText\"]], [256, \"Entries\"]]")
+            .unwrap();
+        let records = records(&value).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!(256, get_u64(&records[0], "id").unwrap());
+        assert_eq!("Entries".to_owned(), get_str(&records[0], "name").unwrap());
+    }
+
+    #[test]
+    fn test_names() {
+        let value = body("[{\"name\": \"TokenBigram\"}, {\"name\": \"TokenMecab\"}]").unwrap();
+        assert_eq!(vec!["TokenBigram".to_owned(), "TokenMecab".to_owned()],
+                   names(&value).unwrap());
+        assert!(names(&body("[{\"id\": 1}]").unwrap()).is_err());
+    }
+}
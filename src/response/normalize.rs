@@ -0,0 +1,129 @@
+use std::str::FromStr;
+use types::char_type::CharType;
+use response::{self, ResponseError};
+
+/// Typed `normalize` result.
+///
+/// `types` and `blanks` are filled with `WITH_TYPES` flag and
+/// `checks` is filled with `WITH_CHECKS` flag.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::normalize::NormalizeResult;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                {"normalized": "abc", "types": ["alpha", "alpha|blank", "alpha"],
+///                 "checks": [1, 2, 1]}]"#;
+/// let result: NormalizeResult = json.parse().unwrap();
+/// assert_eq!(Some(3), result.original_offset(2));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NormalizeResult {
+    pub normalized: String,
+    /// Character type of each normalized character.
+    pub types: Vec<CharType>,
+    /// Whether each normalized character is followed by blank in the original string.
+    pub blanks: Vec<bool>,
+    /// Byte length in the original string for each normalized byte.
+    /// It is `0` for a byte which is not the first byte of a character.
+    pub checks: Vec<i64>,
+}
+
+impl NormalizeResult {
+    /// Maps a byte offset in `normalized` to a byte offset in the original string.
+    ///
+    /// Returns `None` when `checks` is empty, `normalized_offset` is out of range or
+    /// it does not point to the first byte of a character.
+    /// The end of `normalized` is mapped to the end of the original string.
+    pub fn original_offset(&self, normalized_offset: usize) -> Option<usize> {
+        if self.checks.is_empty() || normalized_offset > self.checks.len() {
+            return None;
+        }
+        if normalized_offset < self.checks.len() && self.checks[normalized_offset] <= 0 {
+            return None;
+        }
+        Some(self.checks[..normalized_offset]
+            .iter()
+            .filter(|check| **check > 0)
+            .fold(0, |offset, check| offset + *check as usize))
+    }
+}
+
+impl FromStr for NormalizeResult {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<NormalizeResult, ResponseError> {
+        let body = try!(response::body(s));
+        let normalized = try!(body.get("normalized")
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| ResponseError::Malformed("`normalized` must be a string".to_owned())));
+        let (types, blanks) = response::char_types(body.get("types"));
+        let checks = match body.get("checks").and_then(|c| c.as_array()) {
+            Some(checks) => checks.iter().filter_map(|c| c.as_i64()).collect(),
+            None => vec![],
+        };
+        Ok(NormalizeResult {
+            normalized: normalized.to_owned(),
+            types: types,
+            blanks: blanks,
+            checks: checks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::char_type::CharType;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       {"normalized": "abあ",
+                        "types": ["alpha", "alpha|blank", "hiragana"],
+                        "checks": [1, 2, 3, 0, 0]}]"#;
+        let result: NormalizeResult = json.parse().unwrap();
+        let expected = NormalizeResult {
+            normalized: "abあ".to_owned(),
+            types: vec![CharType::Alpha, CharType::Alpha, CharType::Hiragana],
+            blanks: vec![false, true, false],
+            checks: vec![1, 2, 3, 0, 0],
+        };
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_from_str_without_flags() {
+        let json = r#"{"normalized": "abc", "types": [], "checks": []}"#;
+        let result: NormalizeResult = json.parse().unwrap();
+        assert!(result.types.is_empty());
+        assert_eq!(None, result.original_offset(0));
+    }
+
+    #[test]
+    fn test_original_offset() {
+        // "Ａ b" is normalized into "ab".
+        let result = NormalizeResult {
+            normalized: "ab".to_owned(),
+            types: vec![CharType::Alpha, CharType::Alpha],
+            blanks: vec![true, false],
+            checks: vec![4, 1],
+        };
+        assert_eq!(Some(0), result.original_offset(0));
+        assert_eq!(Some(4), result.original_offset(1));
+        assert_eq!(Some(5), result.original_offset(2));
+        assert_eq!(None, result.original_offset(3));
+    }
+
+    #[test]
+    fn test_original_offset_in_multibyte_char() {
+        let result = NormalizeResult {
+            normalized: "あ".to_owned(),
+            types: vec![CharType::Hiragana],
+            blanks: vec![false],
+            checks: vec![3, 0, 0],
+        };
+        assert_eq!(Some(0), result.original_offset(0));
+        assert_eq!(None, result.original_offset(1));
+        assert_eq!(Some(3), result.original_offset(3));
+    }
+}
@@ -0,0 +1,72 @@
+use std::str::FromStr;
+use types::normalizer_type::NormalizerType;
+use response::{self, ResponseError};
+
+/// Typed `normalizer_list` result.
+///
+/// Plugin-provided normalizers are decoded as `ExtNormalizerType`
+/// unless they are enabled with a feature flag.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::normalizer_list::NormalizerListResponse;
+/// use ruroonga_command::types::normalizer_type::NormalizerType;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [{"name": "NormalizerAuto"}, {"name": "NormalizerNFKC51"}]]"#;
+/// let response: NormalizerListResponse = json.parse().unwrap();
+/// assert!(response.contains(&NormalizerType::Auto));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NormalizerListResponse {
+    pub normalizers: Vec<NormalizerType>,
+}
+
+impl NormalizerListResponse {
+    pub fn contains(&self, normalizer: &NormalizerType) -> bool {
+        self.normalizers.contains(normalizer)
+    }
+
+    /// Returns normalizers in `required` which are not available on the server.
+    ///
+    /// `TableCreateCommand::required_normalizers` lists normalizers which a table needs.
+    pub fn missing(&self, required: &[NormalizerType]) -> Vec<NormalizerType> {
+        required.iter().filter(|n| !self.contains(n)).cloned().collect()
+    }
+}
+
+impl FromStr for NormalizerListResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<NormalizerListResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let mut normalizers = vec![];
+        for name in try!(response::names(&body)) {
+            if let Ok(normalizer) = NormalizerType::from_str(&name) {
+                normalizers.push(normalizer);
+            }
+        }
+        Ok(NormalizerListResponse { normalizers: normalizers })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::normalizer_type::NormalizerType;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[{"name": "NormalizerAuto"}, {"name": "NormalizerNFKC100"}]"#;
+        let response: NormalizerListResponse = json.parse().unwrap();
+        let expected = vec![NormalizerType::Auto,
+                            NormalizerType::ExtNormalizerType("NormalizerNFKC100".to_owned())];
+        assert_eq!(expected, response.normalizers);
+    }
+
+    #[test]
+    fn test_missing() {
+        let response = NormalizerListResponse { normalizers: vec![NormalizerType::Auto] };
+        assert_eq!(vec![NormalizerType::NFKC51],
+                   response.missing(&[NormalizerType::Auto, NormalizerType::NFKC51]));
+    }
+}
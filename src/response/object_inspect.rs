@@ -0,0 +1,299 @@
+use std::str::FromStr;
+use serde_json::Value;
+use types::table_flag_type::TableFlagType;
+use types::data_type::DataType;
+use response::{self, ResponseError};
+
+/// Inspected database.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DatabaseInspection {
+    /// The table which manages names of objects in the database.
+    pub name_table: TableInspection,
+    pub disk_usage: Option<u64>,
+}
+
+/// Inspected table.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TableInspection {
+    /// It is `None` for the name table of database.
+    pub id: Option<u64>,
+    pub name: String,
+    /// Raw type name such as `table:hash_key`.
+    pub table_type: String,
+    pub key_type: Option<DataType>,
+    pub value_type: Option<DataType>,
+    pub n_records: u64,
+    pub disk_usage: Option<u64>,
+}
+
+impl TableInspection {
+    /// Returns the flag which corresponds to `table_type`.
+    pub fn flag(&self) -> TableFlagType {
+        match self.table_type.as_str() {
+            "table:no_key" => TableFlagType::NoKey,
+            "table:hash_key" => TableFlagType::HashKey,
+            "table:pat_key" => TableFlagType::PatKey,
+            "table:dat_key" => TableFlagType::DatKey,
+            s => TableFlagType::ExtTableFlagType(s.to_owned()),
+        }
+    }
+}
+
+/// Inspected column.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ColumnInspection {
+    pub id: u64,
+    pub name: String,
+    /// The table which has this column.
+    pub table: String,
+    pub full_name: String,
+    /// `scalar`, `vector` or `index`.
+    pub column_type: String,
+    pub value_type: Option<DataType>,
+    /// Compression type such as `zlib`. It is `None` when not compressed.
+    pub compress: Option<String>,
+    /// Full names of index sources. It is empty except index column.
+    pub sources: Vec<String>,
+    pub disk_usage: Option<u64>,
+}
+
+/// Inspected type.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TypeInspection {
+    pub id: u64,
+    pub name: String,
+    pub size: u64,
+}
+
+/// Typed `object_inspect` result.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::object_inspect::ObjectInspection;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                {"id": 256, "name": "Users",
+///                 "type": {"id": 48, "name": "table:hash_key"},
+///                 "key": {"type": {"id": 14, "name": "ShortText",
+///                                  "type": {"id": 32, "name": "type"}, "size": 4096}},
+///                 "value": {"type": null}, "n_records": 3, "disk_usage": 4243456}]"#;
+/// match json.parse().unwrap() {
+///     ObjectInspection::Table(table) => assert_eq!(3, table.n_records),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ObjectInspection {
+    Database(DatabaseInspection),
+    Table(TableInspection),
+    Column(ColumnInspection),
+    Type(TypeInspection),
+}
+
+fn malformed(name: &str) -> ResponseError {
+    ResponseError::Malformed(format!("`{}` is missing or has an unexpected type", name))
+}
+
+fn get_u64(object: &Value, pointer: &str) -> Result<u64, ResponseError> {
+    object.pointer(pointer).and_then(|v| v.as_u64()).ok_or_else(|| malformed(pointer))
+}
+
+fn get_str(object: &Value, pointer: &str) -> Result<String, ResponseError> {
+    object.pointer(pointer)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_owned())
+        .ok_or_else(|| malformed(pointer))
+}
+
+fn get_type(object: &Value, pointer: &str) -> Option<DataType> {
+    object.pointer(pointer)
+        .and_then(|v| v.as_str())
+        .and_then(|s| DataType::from_str(s).ok())
+}
+
+fn table(object: &Value) -> Result<TableInspection, ResponseError> {
+    Ok(TableInspection {
+        id: object.get("id").and_then(|v| v.as_u64()),
+        name: object.get("name").and_then(|v| v.as_str()).unwrap_or("").to_owned(),
+        table_type: try!(get_str(object, "/type/name")),
+        key_type: get_type(object, "/key/type/name"),
+        value_type: get_type(object, "/value/type/name"),
+        n_records: try!(get_u64(object, "/n_records")),
+        disk_usage: object.get("disk_usage").and_then(|v| v.as_u64()),
+    })
+}
+
+fn column(object: &Value) -> Result<ColumnInspection, ResponseError> {
+    let sources = match object.get("sources").and_then(|v| v.as_array()) {
+        Some(sources) => {
+            sources.iter()
+                .filter_map(|s| s.get("full_name").and_then(|n| n.as_str()))
+                .map(|s| s.to_owned())
+                .collect()
+        }
+        None => vec![],
+    };
+    Ok(ColumnInspection {
+        id: try!(get_u64(object, "/id")),
+        name: try!(get_str(object, "/name")),
+        table: try!(get_str(object, "/table/name")),
+        full_name: try!(get_str(object, "/full_name")),
+        column_type: try!(get_str(object, "/type/name")),
+        value_type: get_type(object, "/value/type/name"),
+        compress: object.pointer("/value/compress")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_owned()),
+        sources: sources,
+        disk_usage: object.get("disk_usage").and_then(|v| v.as_u64()),
+    })
+}
+
+fn inspection(object: &Value) -> Result<ObjectInspection, ResponseError> {
+    let type_name = try!(get_str(object, "/type/name"));
+    if type_name == "db" {
+        let name_table = try!(object.get("name_table").ok_or_else(|| malformed("name_table")));
+        Ok(ObjectInspection::Database(DatabaseInspection {
+            name_table: try!(table(name_table)),
+            disk_usage: object.get("disk_usage").and_then(|v| v.as_u64()),
+        }))
+    } else if type_name.starts_with("table:") {
+        Ok(ObjectInspection::Table(try!(table(object))))
+    } else if object.get("full_name").is_some() {
+        Ok(ObjectInspection::Column(try!(column(object))))
+    } else if type_name == "type" {
+        Ok(ObjectInspection::Type(TypeInspection {
+            id: try!(get_u64(object, "/id")),
+            name: try!(get_str(object, "/name")),
+            size: try!(get_u64(object, "/size")),
+        }))
+    } else {
+        Err(ResponseError::Malformed(format!("unknown object type: {}", type_name)))
+    }
+}
+
+impl FromStr for ObjectInspection {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<ObjectInspection, ResponseError> {
+        let body = try!(response::body(s));
+        inspection(&body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::table_flag_type::TableFlagType;
+    use types::data_type::DataType;
+
+    #[test]
+    fn test_database() {
+        let json = r#"{"type": {"id": 55, "name": "db"},
+                       "name_table": {"id": null, "name": "",
+                                      "type": {"id": 49, "name": "table:pat_key"},
+                                      "key": {"type": null}, "value": {"type": null},
+                                      "n_records": 256},
+                       "disk_usage": 15691776}"#;
+        let expected = ObjectInspection::Database(DatabaseInspection {
+            name_table: TableInspection {
+                id: None,
+                name: "".to_owned(),
+                table_type: "table:pat_key".to_owned(),
+                key_type: None,
+                value_type: None,
+                n_records: 256,
+                disk_usage: None,
+            },
+            disk_usage: Some(15691776),
+        });
+        assert_eq!(expected, json.parse().unwrap());
+    }
+
+    #[test]
+    fn test_table() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       {"id": 256, "name": "Users",
+                        "type": {"id": 48, "name": "table:hash_key"},
+                        "key": {"type": {"id": 14, "name": "ShortText",
+                                         "type": {"id": 32, "name": "type"}, "size": 4096},
+                                "total_size": 0, "max_total_size": 4294967295},
+                        "value": {"type": null}, "n_records": 3, "disk_usage": 4243456}]"#;
+        let table = match json.parse().unwrap() {
+            ObjectInspection::Table(table) => table,
+            _ => panic!("A table must be decoded as a table!"),
+        };
+        assert_eq!(Some(256), table.id);
+        assert_eq!(TableFlagType::HashKey, table.flag());
+        assert_eq!(Some(DataType::ShortText), table.key_type);
+        assert_eq!(None, table.value_type);
+        assert_eq!(3, table.n_records);
+        assert_eq!(Some(4243456), table.disk_usage);
+    }
+
+    #[test]
+    fn test_index_column() {
+        let json = r#"{"id": 259, "name": "index",
+                       "table": {"id": 258, "name": "Terms",
+                                 "type": {"id": 49, "name": "table:pat_key"}},
+                       "full_name": "Terms.index",
+                       "type": {"name": "index",
+                                "raw": {"id": 72, "name": "column:index"}},
+                       "value": {"type": {"id": 256, "name": "Memos",
+                                          "type": {"id": 48, "name": "table:hash_key"}},
+                                 "section": false, "weight": false, "position": true,
+                                 "size": "normal"},
+                       "sources": [{"id": 257, "name": "title",
+                                    "table": {"id": 256, "name": "Memos"},
+                                    "full_name": "Memos.title"}],
+                       "disk_usage": 5869568}"#;
+        let expected = ObjectInspection::Column(ColumnInspection {
+            id: 259,
+            name: "index".to_owned(),
+            table: "Terms".to_owned(),
+            full_name: "Terms.index".to_owned(),
+            column_type: "index".to_owned(),
+            value_type: Some(DataType::ExtDataType("Memos".to_owned())),
+            compress: None,
+            sources: vec!["Memos.title".to_owned()],
+            disk_usage: Some(5869568),
+        });
+        assert_eq!(expected, json.parse().unwrap());
+    }
+
+    #[test]
+    fn test_compressed_column() {
+        let json = r#"{"id": 257, "name": "content", "table": {"id": 256, "name": "Memos"},
+                       "full_name": "Memos.content",
+                       "type": {"name": "scalar",
+                                "raw": {"id": 65, "name": "column:var_size"}},
+                       "value": {"type": {"id": 16, "name": "LongText"},
+                                 "compress": "zstd"}}"#;
+        match json.parse().unwrap() {
+            ObjectInspection::Column(column) => {
+                assert_eq!(Some(DataType::LongText), column.value_type);
+                assert_eq!(Some("zstd".to_owned()), column.compress);
+                assert!(column.sources.is_empty());
+                assert_eq!(None, column.disk_usage);
+            }
+            _ => panic!("A column must be decoded as a column!"),
+        }
+    }
+
+    #[test]
+    fn test_type() {
+        let json = r#"{"id": 14, "name": "ShortText", "type": {"id": 32, "name": "type"},
+                       "size": 4096}"#;
+        let expected = ObjectInspection::Type(TypeInspection {
+            id: 14,
+            name: "ShortText".to_owned(),
+            size: 4096,
+        });
+        assert_eq!(expected, json.parse().unwrap());
+    }
+
+    #[test]
+    fn test_unknown() {
+        let json = r#"{"id": 1, "name": "x", "type": {"id": 1, "name": "proc"}}"#;
+        let inspection: Result<ObjectInspection, _> = json.parse();
+        assert!(inspection.is_err());
+    }
+}
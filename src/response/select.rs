@@ -0,0 +1,76 @@
+use std::str::FromStr;
+use serde_json::Value;
+use response::{self, ResponseError};
+
+/// Record IDs which are returned from `select` with `_id` in `output_columns`.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::select::SelectIdsResponse;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [[[3], [["_id", "UInt32"]], [1], [4], [5]]]]"#;
+/// let response: SelectIdsResponse = json.parse().unwrap();
+/// assert_eq!(3, response.n_hits);
+/// assert_eq!(vec![1, 4, 5], response.ids);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SelectIdsResponse {
+    /// The number of matched records. It may be larger than `ids` because of `limit`.
+    pub n_hits: u64,
+    pub ids: Vec<u64>,
+}
+
+impl FromStr for SelectIdsResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<SelectIdsResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let result = try!(body.as_array()
+            .and_then(|results| results.first())
+            .and_then(|result| result.as_array())
+            .ok_or_else(|| ResponseError::Malformed("search result is missing".to_owned())));
+        let n_hits = try!(result.first()
+            .and_then(|n_hits| n_hits.as_array())
+            .and_then(|n_hits| n_hits.first())
+            .and_then(|n_hits| n_hits.as_u64())
+            .ok_or_else(|| ResponseError::Malformed("`n_hits` is missing".to_owned())));
+        let rows = Value::Array(result.iter().skip(1).cloned().collect());
+        let mut ids = vec![];
+        for record in try!(response::records(&rows)) {
+            ids.push(try!(response::get_u64(&record, "_id")));
+        }
+        Ok(SelectIdsResponse {
+            n_hits: n_hits,
+            ids: ids,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [[[10], [["_id", "UInt32"], ["title", "ShortText"]],
+                         [2, "Groonga"], [3, "Mroonga"]]]]"#;
+        let response: SelectIdsResponse = json.parse().unwrap();
+        assert_eq!(10, response.n_hits);
+        assert_eq!(vec![2, 3], response.ids);
+    }
+
+    #[test]
+    fn test_from_str_without_records() {
+        let json = r#"[[[0], [["_id", "UInt32"]]]]"#;
+        let response: SelectIdsResponse = json.parse().unwrap();
+        assert_eq!(0, response.n_hits);
+        assert!(response.ids.is_empty());
+    }
+
+    #[test]
+    fn test_from_str_without_id() {
+        let json = r#"[[[1], [["title", "ShortText"]], ["Groonga"]]]"#;
+        assert!(json.parse::<SelectIdsResponse>().is_err());
+    }
+}
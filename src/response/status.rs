@@ -0,0 +1,225 @@
+use std::fmt;
+use std::fmt::Write;
+use std::str::FromStr;
+use serde_json::Value;
+use response::{self, ResponseError};
+use types::groonga_version::GroongaVersion;
+
+/// Typed `status` result.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::status::StatusResponse;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                {"alloc_count": 29, "starttime": 1309737549, "start_time": 1309737549,
+///                 "uptime": 42, "version": "7.0.0", "n_queries": 3,
+///                 "cache_hit_rate": 50.0, "command_version": 1,
+///                 "default_command_version": 1, "max_command_version": 3,
+///                 "n_jobs": 0}]"#;
+/// let status: StatusResponse = json.parse().unwrap();
+/// assert_eq!(42, status.uptime);
+/// assert_eq!(50.0, status.cache_hit_rate);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct StatusResponse {
+    /// The number of memory allocations which are not freed yet.
+    pub alloc_count: u64,
+    /// UNIX time when Groonga is started.
+    pub start_time: u64,
+    /// Alive time in seconds.
+    pub uptime: u64,
+    pub version: String,
+    pub n_queries: u64,
+    /// Cache hit rate in percentage.
+    pub cache_hit_rate: f64,
+    pub command_version: u64,
+    pub default_command_version: u64,
+    pub max_command_version: u64,
+    /// The number of running jobs. It is `None` with older Groonga.
+    pub n_jobs: Option<u64>,
+    /// Size of mapped memory in bytes. It is `None` with older Groonga.
+    pub memory_map_size: Option<u64>,
+}
+
+fn get_u64(object: &Value, name: &str) -> Result<u64, ResponseError> {
+    object.get(name)
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| ResponseError::Malformed(format!("`{}` must be an unsigned integer", name)))
+}
+
+// Escapes `\`, `"` and line feed in a label value of the exposition format.
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// Writes a metric as `# HELP`, `# TYPE` and its sample line.
+fn write_metric<T: fmt::Display>(lines: &mut String,
+                                 name: &str,
+                                 metric_type: &str,
+                                 help: &str,
+                                 labels: &str,
+                                 value: T) {
+    let _ = writeln!(lines, "# HELP groonga_{} {}", name, help);
+    let _ = writeln!(lines, "# TYPE groonga_{} {}", name, metric_type);
+    let _ = writeln!(lines, "groonga_{}{} {}", name, labels, value);
+}
+
+impl StatusResponse {
+    /// Parsed `version`. It is used to check commands with `VersionCheckable`.
+    pub fn groonga_version(&self) -> Option<GroongaVersion> {
+        self.version.parse().ok()
+    }
+
+    /// Formats metrics with Prometheus text exposition format.
+    /// Each metric name is prefixed with `groonga_`.
+    pub fn to_prometheus(&self) -> String {
+        let mut lines = String::new();
+        write_metric(&mut lines,
+                     "uptime_seconds",
+                     "gauge",
+                     "Alive time of Groonga.",
+                     "",
+                     self.uptime);
+        write_metric(&mut lines,
+                     "queries_total",
+                     "counter",
+                     "The number of processed queries.",
+                     "",
+                     self.n_queries);
+        write_metric(&mut lines,
+                     "cache_hit_rate",
+                     "gauge",
+                     "Query cache hit rate in percentage.",
+                     "",
+                     self.cache_hit_rate);
+        write_metric(&mut lines,
+                     "command_version",
+                     "gauge",
+                     "Current command version.",
+                     "",
+                     self.command_version);
+        write_metric(&mut lines,
+                     "alloc_count",
+                     "gauge",
+                     "The number of memory allocations which are not freed yet.",
+                     "",
+                     self.alloc_count);
+        if let Some(memory_map_size) = self.memory_map_size {
+            write_metric(&mut lines,
+                         "memory_map_size_bytes",
+                         "gauge",
+                         "Size of mapped memory.",
+                         "",
+                         memory_map_size);
+        }
+        if let Some(n_jobs) = self.n_jobs {
+            write_metric(&mut lines, "jobs", "gauge", "The number of running jobs.", "", n_jobs);
+        }
+        write_metric(&mut lines,
+                     "version_info",
+                     "gauge",
+                     "Groonga version.",
+                     &format!("{{version=\"{}\"}}", escape_label_value(&self.version)),
+                     1);
+        lines
+    }
+}
+
+impl FromStr for StatusResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<StatusResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let start_time = match body.get("start_time") {
+            Some(_) => try!(get_u64(&body, "start_time")),
+            None => try!(get_u64(&body, "starttime")),
+        };
+        Ok(StatusResponse {
+            alloc_count: try!(get_u64(&body, "alloc_count")),
+            start_time: start_time,
+            uptime: try!(get_u64(&body, "uptime")),
+            version: try!(body.get("version")
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_owned())
+                .ok_or_else(|| ResponseError::Malformed("`version` must be a string".to_owned()))),
+            n_queries: try!(get_u64(&body, "n_queries")),
+            cache_hit_rate: try!(body.get("cache_hit_rate")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| {
+                    ResponseError::Malformed("`cache_hit_rate` must be a number".to_owned())
+                })),
+            command_version: try!(get_u64(&body, "command_version")),
+            default_command_version: try!(get_u64(&body, "default_command_version")),
+            max_command_version: try!(get_u64(&body, "max_command_version")),
+            n_jobs: body.get("n_jobs").and_then(|v| v.as_u64()),
+            memory_map_size: body.get("memory_map_size").and_then(|v| v.as_u64()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::groonga_version::GroongaVersion;
+
+    fn status() -> StatusResponse {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       {"alloc_count": 29, "starttime": 1309737549,
+                        "start_time": 1309737549, "uptime": 42, "version": "7.0.0",
+                        "n_queries": 3, "cache_hit_rate": 50.0, "command_version": 1,
+                        "default_command_version": 1, "max_command_version": 3,
+                        "n_jobs": 2, "memory_map_size": 4096}]"#;
+        json.parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = StatusResponse {
+            alloc_count: 29,
+            start_time: 1309737549,
+            uptime: 42,
+            version: "7.0.0".to_owned(),
+            n_queries: 3,
+            cache_hit_rate: 50.0,
+            command_version: 1,
+            default_command_version: 1,
+            max_command_version: 3,
+            n_jobs: Some(2),
+            memory_map_size: Some(4096),
+        };
+        assert_eq!(expected, status());
+        assert_eq!(Some(GroongaVersion::new(7, 0, 0)), status().groonga_version());
+    }
+
+    #[test]
+    fn test_from_str_with_older_groonga() {
+        let json = r#"{"alloc_count": 29, "starttime": 1309737549, "uptime": 42,
+                       "version": "4.0.0", "n_queries": 0, "cache_hit_rate": 0.0,
+                       "command_version": 1, "default_command_version": 1,
+                       "max_command_version": 2}"#;
+        let status: StatusResponse = json.parse().unwrap();
+        assert_eq!(1309737549, status.start_time);
+        assert_eq!(None, status.n_jobs);
+        assert_eq!(None, status.memory_map_size);
+    }
+
+    #[test]
+    fn test_to_prometheus() {
+        let lines = status().to_prometheus();
+        assert!(lines.contains("# TYPE groonga_uptime_seconds gauge\ngroonga_uptime_seconds 42\n"));
+        assert!(lines.contains("# TYPE groonga_queries_total counter\ngroonga_queries_total 3\n"));
+        assert!(lines.contains("groonga_cache_hit_rate 50\n"));
+        assert!(lines.contains("groonga_memory_map_size_bytes 4096\n"));
+        assert!(lines.contains("groonga_jobs 2\n"));
+        assert!(lines.ends_with("groonga_version_info{version=\"7.0.0\"} 1\n"));
+    }
+
+    #[test]
+    fn test_to_prometheus_with_escaped_version() {
+        let mut status = status();
+        status.version = "7.0.0-\"dev\"\\\n".to_owned();
+        let lines = status.to_prometheus();
+        let expected = r#"groonga_version_info{version="7.0.0-\"dev\"\\\n"} 1"#;
+        assert!(lines.ends_with(&format!("{}\n", expected)));
+    }
+}
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+use types::table_flag_type::TableFlagType;
+use types::data_type::DataType;
+use types::tokenizer_type::TokenizerType;
+use types::normalizer_type::NormalizerType;
+use response::{self, ResponseError};
+
+/// A table which is returned from `table_list`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Table {
+    pub id: u64,
+    pub name: String,
+    pub path: String,
+    pub flags: Vec<TableFlagType>,
+    /// Key type of this table. It is `None` for `TABLE_NO_KEY`.
+    pub domain: Option<DataType>,
+    /// Value type of this table.
+    pub range: Option<DataType>,
+    pub default_tokenizer: Option<TokenizerType>,
+    pub normalizer: Option<NormalizerType>,
+}
+
+/// Typed `table_list` result.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::table_list::TableListResponse;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [[["id", "UInt32"], ["name", "ShortText"], ["path", "ShortText"],
+///                  ["flags", "ShortText"], ["domain", "ShortText"], ["range", "ShortText"],
+///                  ["default_tokenizer", "ShortText"], ["normalizer", "ShortText"]],
+///                 [256, "Entries", "/tmp/db.0000100", "TABLE_HASH_KEY|PERSISTENT",
+///                  "ShortText", null, null, null]]]"#;
+/// let response: TableListResponse = json.parse().unwrap();
+/// assert_eq!("Entries", response.tables[0].name);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TableListResponse {
+    pub tables: Vec<Table>,
+}
+
+impl FromStr for TableListResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<TableListResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let mut tables = vec![];
+        for record in try!(response::records(&body)) {
+            let flags = try!(response::get_str(&record, "flags"));
+            let domain = try!(response::get_str(&record, "domain"));
+            let range = try!(response::get_str(&record, "range"));
+            let tokenizer = try!(response::get_str(&record, "default_tokenizer"));
+            let normalizer = try!(response::get_str(&record, "normalizer"));
+            tables.push(Table {
+                id: try!(response::get_u64(&record, "id")),
+                name: try!(response::get_str(&record, "name")),
+                path: try!(response::get_str(&record, "path")),
+                flags: response::split_flags(&flags)
+                    .into_iter()
+                    .filter_map(|f| TableFlagType::from_str(f).ok())
+                    .collect(),
+                domain: DataType::from_str(&domain).ok(),
+                range: DataType::from_str(&range).ok(),
+                default_tokenizer: TokenizerType::from_str(&tokenizer).ok(),
+                normalizer: NormalizerType::from_str(&normalizer).ok(),
+            });
+        }
+        Ok(TableListResponse { tables: tables })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::table_flag_type::TableFlagType;
+    use types::data_type::DataType;
+    use types::tokenizer_type::TokenizerType;
+    use types::normalizer_type::NormalizerType;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [[["id", "UInt32"], ["name", "ShortText"], ["path", "ShortText"],
+                         ["flags", "ShortText"], ["domain", "ShortText"],
+                         ["range", "ShortText"], ["default_tokenizer", "ShortText"],
+                         ["normalizer", "ShortText"]],
+                        [256, "Entries", "/tmp/db.0000100", "TABLE_HASH_KEY|PERSISTENT",
+                         "ShortText", null, null, null],
+                        [257, "Terms", "/tmp/db.0000101", "TABLE_PAT_KEY|PERSISTENT",
+                         "ShortText", null, "TokenBigram", "NormalizerAuto"]]]"#;
+        let response: TableListResponse = json.parse().unwrap();
+        assert_eq!(2, response.tables.len());
+        let entries = &response.tables[0];
+        assert_eq!(None, entries.range);
+        assert_eq!(None, entries.default_tokenizer);
+        assert_eq!(None, entries.normalizer);
+        let expected = Table {
+            id: 257,
+            name: "Terms".to_owned(),
+            path: "/tmp/db.0000101".to_owned(),
+            flags: vec![TableFlagType::PatKey,
+                        TableFlagType::ExtTableFlagType("PERSISTENT".to_owned())],
+            domain: Some(DataType::ShortText),
+            range: None,
+            default_tokenizer: Some(TokenizerType::Bigram),
+            normalizer: Some(NormalizerType::Auto),
+        };
+        assert_eq!(expected, response.tables[1]);
+    }
+
+    #[test]
+    fn test_from_str_without_tables() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [[["id", "UInt32"], ["name", "ShortText"]]]]"#;
+        let response: TableListResponse = json.parse().unwrap();
+        assert!(response.tables.is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+use serde_json::Value;
+use response::{self, ResponseError};
+use types::char_type::CharType;
+
+/// A token which is returned from `tokenize` and `table_tokenize`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Token {
+    pub value: String,
+    pub position: u64,
+    /// Whether this token is searched with prefix search in `GET` mode.
+    pub force_prefix: bool,
+    /// Character types such as `alpha` and `hiragana`.
+    /// It is empty unless `WITH_TYPES` is specified.
+    pub types: Vec<CharType>,
+    /// Whether each character is followed by blank in the original string.
+    /// It is filled with `types`.
+    pub blanks: Vec<bool>,
+}
+
+/// Typed `tokenize` and `table_tokenize` result.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::tokenize::TokenizeResponse;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [{"value": "Fu", "position": 0, "force_prefix": false},
+///                 {"value": "uz", "position": 1, "force_prefix": false}]]"#;
+/// let response: TokenizeResponse = json.parse().unwrap();
+/// assert_eq!("uz", response.tokens[1].value);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TokenizeResponse {
+    pub tokens: Vec<Token>,
+}
+
+fn token(value: &Value) -> Result<Token, ResponseError> {
+    let (types, blanks) = response::char_types(value.get("types"));
+    Ok(Token {
+        value: try!(value.get("value")
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_owned())
+            .ok_or_else(|| ResponseError::Malformed("`value` must be a string".to_owned()))),
+        position: try!(value.get("position")
+            .and_then(|p| p.as_u64())
+            .ok_or_else(|| {
+                ResponseError::Malformed("`position` must be an unsigned integer".to_owned())
+            })),
+        force_prefix: value.get("force_prefix").and_then(|f| f.as_bool()).unwrap_or(false),
+        types: types,
+        blanks: blanks,
+    })
+}
+
+impl FromStr for TokenizeResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<TokenizeResponse, ResponseError> {
+        let body = try!(response::body(s));
+        // Command version 3 wraps tokens with an object.
+        let tokens = try!(body.get("tokens")
+            .unwrap_or(&body)
+            .as_array()
+            .ok_or_else(|| ResponseError::Malformed("tokens must be an array".to_owned())));
+        let mut result = vec![];
+        for value in tokens {
+            result.push(try!(token(value)));
+        }
+        Ok(TokenizeResponse { tokens: result })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::char_type::CharType;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [{"value": "Fu", "position": 0, "force_prefix": false},
+                        {"value": "u", "position": 1, "force_prefix": true}]]"#;
+        let response: TokenizeResponse = json.parse().unwrap();
+        let expected = vec![Token {
+                                value: "Fu".to_owned(),
+                                position: 0,
+                                force_prefix: false,
+                                types: vec![],
+                                blanks: vec![],
+                            },
+                            Token {
+                                value: "u".to_owned(),
+                                position: 1,
+                                force_prefix: true,
+                                types: vec![],
+                                blanks: vec![],
+                            }];
+        assert_eq!(expected, response.tokens);
+    }
+
+    #[test]
+    fn test_from_str_with_types() {
+        let json = r#"{"header": {"return_code": 0, "start_time": 0.0, "elapsed_time": 0.0},
+                       "body": {"tokens": [{"value": "ab", "position": 0,
+                                            "force_prefix": false,
+                                            "types": ["alpha", "alpha|blank"]}]}}"#;
+        let response: TokenizeResponse = json.parse().unwrap();
+        assert_eq!(vec![CharType::Alpha, CharType::Alpha], response.tokens[0].types);
+        assert_eq!(vec![false, true], response.tokens[0].blanks);
+    }
+
+    #[test]
+    fn test_from_str_without_position() {
+        let json = r#"[{"value": "Fu"}]"#;
+        let response: Result<TokenizeResponse, _> = json.parse();
+        assert!(response.is_err());
+    }
+}
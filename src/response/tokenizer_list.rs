@@ -0,0 +1,87 @@
+use std::str::FromStr;
+use types::tokenizer_type::TokenizerType;
+use response::{self, ResponseError};
+
+/// Typed `tokenizer_list` result.
+///
+/// Plugin-provided tokenizers are decoded as `ExtUserDefined`.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::response::tokenizer_list::TokenizerListResponse;
+/// use ruroonga_command::types::tokenizer_type::TokenizerType;
+/// let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+///                [{"name": "TokenBigram"}, {"name": "TokenMecab"}]]"#;
+/// let response: TokenizerListResponse = json.parse().unwrap();
+/// assert!(response.contains(&TokenizerType::Mecab));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TokenizerListResponse {
+    pub tokenizers: Vec<TokenizerType>,
+}
+
+impl TokenizerListResponse {
+    pub fn contains(&self, tokenizer: &TokenizerType) -> bool {
+        self.tokenizers.contains(tokenizer)
+    }
+
+    /// Returns tokenizers in `required` which are not available on the server.
+    ///
+    /// `TableCreateCommand::required_tokenizers` lists tokenizers which a table needs.
+    pub fn missing(&self, required: &[TokenizerType]) -> Vec<TokenizerType> {
+        required.iter().filter(|t| !self.contains(t)).cloned().collect()
+    }
+}
+
+impl FromStr for TokenizerListResponse {
+    type Err = ResponseError;
+    fn from_str(s: &str) -> Result<TokenizerListResponse, ResponseError> {
+        let body = try!(response::body(s));
+        let mut tokenizers = vec![];
+        for name in try!(response::names(&body)) {
+            if let Ok(tokenizer) = TokenizerType::from_str(&name) {
+                tokenizers.push(tokenizer);
+            }
+        }
+        Ok(TokenizerListResponse { tokenizers: tokenizers })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::tokenizer_type::TokenizerType;
+    use table_create::TableCreateCommand;
+
+    #[test]
+    fn test_from_str() {
+        let json = r#"[[0, 1337566253.89858, 0.000355720520019531],
+                       [{"name": "TokenDelimit"}, {"name": "TokenBigram"},
+                        {"name": "TokenNgram"}]]"#;
+        let response: TokenizerListResponse = json.parse().unwrap();
+        let expected = vec![TokenizerType::Delimit,
+                            TokenizerType::Bigram,
+                            TokenizerType::ExtUserDefined("TokenNgram".to_owned())];
+        assert_eq!(expected, response.tokenizers);
+    }
+
+    #[test]
+    fn test_missing() {
+        let response = TokenizerListResponse {
+            tokenizers: vec![TokenizerType::Bigram, TokenizerType::Delimit],
+        };
+        assert!(response.contains(&TokenizerType::Bigram));
+        assert_eq!(vec![TokenizerType::Mecab],
+                   response.missing(&[TokenizerType::Bigram, TokenizerType::Mecab]));
+    }
+
+    #[test]
+    fn test_missing_with_table_create() {
+        let response = TokenizerListResponse { tokenizers: vec![TokenizerType::Bigram] };
+        let table_create = TableCreateCommand::new("Lexicon".to_string())
+            .default_tokenizer(TokenizerType::Mecab);
+        assert_eq!(vec![TokenizerType::Mecab],
+                   response.missing(&table_create.required_tokenizers()));
+    }
+}
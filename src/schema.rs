@@ -55,6 +55,7 @@ impl Commandable for SchemaCommand {
 
 request_cancellable!(SchemaCommand);
 request_timeoutable!(SchemaCommand);
+version_checkable!(SchemaCommand);
 
 #[cfg(test)]
 mod test {
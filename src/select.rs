@@ -17,9 +17,13 @@ use selectable::labeled_drilldownable::LabeledDrilldownable;
 use selectable::labeled_drilldown_builder::LabeledDrilldownBuilder;
 use selectable::labeled_drilldown_sequencable::LabeledDrilldownSequencable;
 use selectable::labeled_drilldown_sequence_builder::LabeledDrilldownSequenceBuilder;
-use selectable::staged_columns::StagedColumns;
+use selectable::labeled_drilldown_tree::{LabeledDrilldownTree, LabeledDrilldownTreeError};
+use selectable::staged_columns::{StagedColumns, StagedColumnsError};
 use selectable::staged_columns_builder::StagedColumnsBuilder;
 use selectable::staged_columnable::StagedColumnable;
+use selectable::staged_columns_sequence_builder::{StagedColumnsSequenceBuilder,
+                                                  StagedColumnsSequenceError};
+use selectable::staged_columns_sequencable::StagedColumnsSequencable;
 use std::ops::Add;
 use extendable::Extendable;
 use request_cancellable::RequestCancellable;
@@ -197,14 +201,35 @@ impl Add<Vec<LabeledDrilldown>> for SelectCommand {
     }
 }
 
+impl Add<LabeledDrilldownTree> for SelectCommand {
+    type Output = Result<LabeledDrilldownSequenceBuilder, LabeledDrilldownTreeError>;
+
+    fn add(self,
+           rhs: LabeledDrilldownTree)
+           -> Result<LabeledDrilldownSequenceBuilder, LabeledDrilldownTreeError> {
+        let drilldowns = try!(rhs.flatten());
+        Ok(LabeledDrilldownSequenceBuilder::new(DrilldownUsable::Select(self), drilldowns))
+    }
+}
+
 impl Add<StagedColumns> for SelectCommand {
-    type Output = StagedColumnsBuilder;
+    type Output = Result<StagedColumnsBuilder, StagedColumnsError>;
 
-    fn add(self, rhs: StagedColumns) -> StagedColumnsBuilder {
+    fn add(self, rhs: StagedColumns) -> Result<StagedColumnsBuilder, StagedColumnsError> {
         StagedColumnsBuilder::new(DrilldownUsable::Select(self), rhs)
     }
 }
 
+impl Add<Vec<StagedColumns>> for SelectCommand {
+    type Output = Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError>;
+
+    fn add(self,
+           rhs: Vec<StagedColumns>)
+           -> Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError> {
+        StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(self), rhs)
+    }
+}
+
 impl Drilldownable for SelectCommand {
     fn with_drilldown(self, rhs: Drilldown) -> DrilldownBuilder {
         DrilldownBuilder::new(DrilldownUsable::Select(self), rhs)
@@ -227,14 +252,26 @@ impl LabeledDrilldownSequencable for SelectCommand {
 }
 
 impl StagedColumnable for SelectCommand {
-    fn with_staged_columns(self, rhs: StagedColumns) -> StagedColumnsBuilder {
+    fn with_staged_columns(self,
+                           rhs: StagedColumns)
+                           -> Result<StagedColumnsBuilder, StagedColumnsError> {
         StagedColumnsBuilder::new(DrilldownUsable::Select(self), rhs)
     }
 }
 
+impl StagedColumnsSequencable for SelectCommand {
+    fn with_staged_columns_sequence
+        (self,
+         rhs: Vec<StagedColumns>)
+         -> Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError> {
+        StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(self), rhs)
+    }
+}
+
 extendable!(SelectCommand);
 request_cancellable!(SelectCommand);
 request_timeoutable!(SelectCommand);
+version_checkable!(SelectCommand);
 
 #[cfg(test)]
 mod test {
@@ -253,6 +290,14 @@ mod test {
     use selectable::labeled_drilldown_builder::LabeledDrilldownBuilder;
     use extendable::Extendable;
     use selectable::drilldown_type::DrilldownUsable;
+    use selectable::labeled_drilldown_sequence_builder::LabeledDrilldownSequenceBuilder;
+    use selectable::labeled_drilldown_tree::LabeledDrilldownTree;
+    use selectable::staged_columns::{StagedColumns, StagedColumnsError, WindowFunction};
+    use selectable::staged_columns::WindowableColumn::{Value, Window};
+    use selectable::staged_columnable::StagedColumnable;
+    use selectable::staged_columns_sequence_builder::StagedColumnsSequenceBuilder;
+    use types::data_type::DataType;
+    use types::stage_type::StageType;
 
     #[test]
     fn test_new() {
@@ -551,6 +596,61 @@ mod test {
         assert_eq!(drilldownable, drilldown_builder);
     }
 
+    #[test]
+    fn test_add_ops_staged_columns_sequence() {
+        let select = SelectCommand::new("Items".to_string());
+        let staged_columns = vec![(StagedColumns::new("price_with_tax".to_string(),
+                                                      StageType::Output,
+                                                      DataType::UInt32,
+                                                      Value("'price * 1.08'".to_string())))];
+        let ops_builder = (select.to_owned() + staged_columns.to_owned()).unwrap().build();
+        let sequence_builder =
+            StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select.to_owned()),
+                                              staged_columns.to_owned())
+                .unwrap()
+                .build();
+        assert_eq!(ops_builder, sequence_builder);
+    }
+
+    #[test]
+    fn test_add_ops_staged_columns() {
+        let select = SelectCommand::new("Items".to_string());
+        let price = StagedColumns::new("price_with_tax".to_string(),
+                                       StageType::Output,
+                                       DataType::UInt32,
+                                       Value("'price * 1.08'".to_string()));
+        assert!((select.to_owned() + price.to_owned()).is_ok());
+        let nth_record = StagedColumns::new("nth_record".to_string(),
+                                            StageType::Output,
+                                            DataType::UInt32,
+                                            Window(WindowFunction::RecordNumber));
+        let expected = StagedColumnsError::WindowFunctionUnavailable(StageType::Output);
+        match select.to_owned() + nth_record.to_owned() {
+            Err(e) => assert_eq!(expected, e),
+            Ok(_) => panic!("Window function must be rejected in output stage!"),
+        }
+        match select.with_staged_columns(nth_record) {
+            Err(e) => assert_eq!(expected, e),
+            Ok(_) => panic!("Window function must be rejected in output stage!"),
+        }
+    }
+
+    #[test]
+    fn test_add_ops_labeled_drilldown_tree() {
+        let select = SelectCommand::new("Memos".to_string());
+        let tree = LabeledDrilldownTree::new()
+            .root(LabeledDrilldown::new("tag".to_string()).keys(vec![("tag".to_string())]))
+            .child("tag".to_string(),
+                   LabeledDrilldown::new("category".to_string())
+                       .keys(vec![("category".to_string())]));
+        let ops_builder = (select.to_owned() + tree.to_owned()).unwrap().build();
+        let sequence_builder =
+            LabeledDrilldownSequenceBuilder::new(DrilldownUsable::Select(select.to_owned()),
+                                                 tree.flatten().unwrap())
+                .build();
+        assert_eq!(ops_builder, sequence_builder);
+    }
+
     #[test]
     fn test_extendable() {
         let mut arg: HashMap<String, String> = HashMap::new();
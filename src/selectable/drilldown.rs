@@ -70,6 +70,18 @@ impl Drilldown {
         self
     }
 
+    pub fn calc_targets(mut self, targets: Vec<String>) -> Drilldown {
+        let string = util::split_values_vec(targets);
+        self.arguments.insert("drilldown_calc_target".to_string(), string.to_owned());
+        self
+    }
+
+    pub fn max_n_target_records(mut self, n: i64) -> Drilldown {
+        let string = format!("{}", n);
+        self.arguments.insert("drilldown_max_n_target_records".to_string(), string.to_owned());
+        self
+    }
+
     pub fn build(self) -> Query {
         let mut query: Query = vec![];
         for (key, value) in &self.arguments {
@@ -166,6 +178,25 @@ mod test {
         assert_eq!(expected, drilldown);
     }
 
+    #[test]
+    fn test_calc_targets() {
+        let drilldown = Drilldown::new()
+            .calc_targets(vec![("price".to_string()), ("stock".to_string())]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("drilldown_calc_target".to_string(), "price,stock".to_string());
+        let expected = Drilldown { arguments: arg };
+        assert_eq!(expected, drilldown);
+    }
+
+    #[test]
+    fn test_max_n_target_records() {
+        let drilldown = Drilldown::new().max_n_target_records(1000);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("drilldown_max_n_target_records".to_string(), "1000".to_string());
+        let expected = Drilldown { arguments: arg };
+        assert_eq!(expected, drilldown);
+    }
+
     #[test]
     fn test_calc_types() {
         let drilldown = Drilldown::new().calc_types(vec![(CalcType::None)]);
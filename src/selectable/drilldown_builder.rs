@@ -7,6 +7,7 @@ use commandable::Commandable;
 use command_line::CommandLine;
 use selectable::drilldown_type::DrilldownUsable;
 
+#[derive(Clone)]
 pub struct DrilldownBuilder {
     select: DrilldownUsable,
     drilldown: Drilldown,
@@ -76,6 +77,8 @@ impl Commandable for DrilldownBuilder {
     }
 }
 
+version_checkable!(DrilldownBuilder);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -4,6 +4,8 @@ use util;
 use selectable::fragmentable::Fragmentable;
 use selectable::fragmentable::{OrderedFragment, QueryFragment};
 use selectable::pseudo_table::PseudoTable;
+use selectable::staged_columns::{StagedColumns, StagedColumnsError};
+use types::stage_type::StageType;
 use types::drilldown_calc_type::CalcType;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -88,6 +90,55 @@ impl LabeledDrilldown {
         self
     }
 
+    pub fn calc_targets(mut self, targets: Vec<String>) -> LabeledDrilldown {
+        let string = util::split_values_vec(targets);
+        let key = util::labeled_key(self.label.to_owned(), "calc_target".to_string());
+        self.arguments.insert(key, string.to_owned());
+        self
+    }
+
+    pub fn max_n_target_records(mut self, n: i64) -> LabeledDrilldown {
+        let string = format!("{}", n);
+        let key = util::labeled_key(self.label.to_owned(), "max_n_target_records".to_string());
+        self.arguments.insert(key, string.to_owned());
+        self
+    }
+
+    pub fn filter(mut self, filter: String) -> LabeledDrilldown {
+        let encoded = format!("'{}'", filter);
+        let key = util::labeled_key(self.label.to_owned(), "filter".to_string());
+        self.arguments.insert(key, encoded.to_owned());
+        self
+    }
+
+    /// Adds dynamic columns which are calculated against this drilldown result.
+    ///
+    /// Groonga only supports `initial` stage for them.
+    /// Returns `StagedColumnsError::StageUnavailable` for other stages.
+    pub fn columns(mut self,
+                   columns: StagedColumns)
+                   -> Result<LabeledDrilldown, StagedColumnsError> {
+        if *columns.stage() != StageType::Initial {
+            return Err(StagedColumnsError::StageUnavailable(columns.stage().clone()));
+        }
+        try!(columns.validate());
+        for (key, value) in columns.build() {
+            let key = util::labeled_key(self.label.to_owned(), key);
+            self.arguments.insert(key, value);
+        }
+        Ok(self)
+    }
+
+    pub fn label(&self) -> &str {
+        self.label.as_ref()
+    }
+
+    /// A label which is specified with `PseudoTable::table`.
+    pub fn pseudo_table(&self) -> Option<&str> {
+        let key = util::labeled_key(self.label.to_owned(), "table".to_string());
+        self.arguments.get(&key).map(|t| t.as_ref())
+    }
+
     pub fn build(self) -> Query {
         let mut query: Query = vec![];
         for (key, value) in &self.arguments {
@@ -121,6 +172,10 @@ mod test {
     use std::collections::HashMap;
     use command::Query;
     use types::drilldown_calc_type::CalcType;
+    use types::data_type::DataType;
+    use types::stage_type::StageType;
+    use selectable::staged_columns::{StagedColumns, StagedColumnsError};
+    use selectable::staged_columns::WindowableColumn::Value;
 
     #[test]
     fn test_new() {
@@ -234,6 +289,82 @@ mod test {
         assert_eq!(expected, drilldown);
     }
 
+    #[test]
+    fn test_calc_targets() {
+        let label = "label1".to_string();
+        let drilldown = LabeledDrilldown::new(label.to_owned())
+            .calc_targets(vec![("price".to_string()), ("stock".to_string())]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert(format!("drilldowns[{}].calc_target", label.to_owned()),
+                   "price,stock".to_string());
+        let expected = LabeledDrilldown {
+            label: label.to_owned(),
+            arguments: arg,
+        };
+        assert_eq!(expected, drilldown);
+    }
+
+    #[test]
+    fn test_max_n_target_records() {
+        let label = "label1".to_string();
+        let drilldown = LabeledDrilldown::new(label.to_owned()).max_n_target_records(1000);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert(format!("drilldowns[{}].max_n_target_records", label.to_owned()),
+                   "1000".to_string());
+        let expected = LabeledDrilldown {
+            label: label.to_owned(),
+            arguments: arg,
+        };
+        assert_eq!(expected, drilldown);
+    }
+
+    #[test]
+    fn test_filter() {
+        let label = "label1".to_string();
+        let drilldown = LabeledDrilldown::new(label.to_owned())
+            .filter("_nsubrecs > 1".to_string());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert(format!("drilldowns[{}].filter", label.to_owned()),
+                   "'_nsubrecs > 1'".to_string());
+        let expected = LabeledDrilldown {
+            label: label.to_owned(),
+            arguments: arg,
+        };
+        assert_eq!(expected, drilldown);
+    }
+
+    #[test]
+    fn test_columns() {
+        let label = "label1".to_string();
+        let columns = StagedColumns::new("rate".to_string(),
+                                         StageType::Initial,
+                                         DataType::Float,
+                                         Value("'_nsubrecs * 100'".to_string()));
+        let drilldown = LabeledDrilldown::new(label.to_owned()).columns(columns).unwrap();
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("drilldowns[label1].columns[rate].stage".to_string(),
+                   "initial".to_string());
+        arg.insert("drilldowns[label1].columns[rate].type".to_string(),
+                   "Float".to_string());
+        arg.insert("drilldowns[label1].columns[rate].value".to_string(),
+                   "'_nsubrecs * 100'".to_string());
+        let expected = LabeledDrilldown {
+            label: label.to_owned(),
+            arguments: arg,
+        };
+        assert_eq!(expected, drilldown);
+    }
+
+    #[test]
+    fn test_columns_with_filtered_stage() {
+        let columns = StagedColumns::new("rate".to_string(),
+                                         StageType::Filtered,
+                                         DataType::Float,
+                                         Value("'_nsubrecs * 100'".to_string()));
+        let drilldown = LabeledDrilldown::new("label1".to_string()).columns(columns);
+        assert_eq!(Err(StagedColumnsError::StageUnavailable(StageType::Filtered)), drilldown);
+    }
+
     #[test]
     fn test_calc_types() {
         let label = "label1".to_string();
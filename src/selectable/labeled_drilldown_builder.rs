@@ -7,6 +7,7 @@ use commandable::Commandable;
 use command_line::CommandLine;
 use selectable::drilldown_type::DrilldownUsable;
 
+#[derive(Clone)]
 pub struct LabeledDrilldownBuilder {
     select: DrilldownUsable,
     drilldown: LabeledDrilldown,
@@ -76,6 +77,8 @@ impl Commandable for LabeledDrilldownBuilder {
     }
 }
 
+version_checkable!(LabeledDrilldownBuilder);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -84,6 +87,22 @@ mod test {
     use select::SelectCommand;
     use selectable::labeled_drilldown::LabeledDrilldown;
     use selectable::drilldown_type::DrilldownUsable;
+    use types::groonga_version::GroongaVersion;
+    use version_checkable::{VersionCheckable, VersionCheckError};
+
+    #[test]
+    fn test_check_version() {
+        let select = SelectCommand::new("Entries".to_string());
+        let drilldown = LabeledDrilldown::new("tag".to_string())
+            .keys(vec![("tag".to_string())])
+            .filter("_nsubrecs > 1".to_string());
+        let builder = LabeledDrilldownBuilder::new(DrilldownUsable::Select(select), drilldown);
+        assert_eq!(Ok(()), builder.check_version(&GroongaVersion::new(6, 1, 5)));
+        assert_eq!(Err(VersionCheckError::UnsupportedParameter("drilldowns[tag].filter"
+                                                                   .to_string(),
+                                                               GroongaVersion::new(6, 1, 5))),
+                   builder.check_version(&GroongaVersion::new(6, 0, 3)));
+    }
 
     #[test]
     fn test_to_query() {
@@ -7,6 +7,7 @@ use commandable::Commandable;
 use command_line::CommandLine;
 use selectable::drilldown_type::DrilldownUsable;
 
+#[derive(Clone)]
 pub struct LabeledDrilldownSequenceBuilder {
     select: DrilldownUsable,
     drilldowns: Vec<LabeledDrilldown>,
@@ -92,6 +93,8 @@ impl Commandable for LabeledDrilldownSequenceBuilder {
     }
 }
 
+version_checkable!(LabeledDrilldownSequenceBuilder);
+
 #[cfg(test)]
 mod test {
     use super::*;
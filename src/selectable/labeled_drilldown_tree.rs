@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use selectable::labeled_drilldown::LabeledDrilldown;
+use selectable::pseudo_table::PseudoTable;
+use self::LabeledDrilldownTreeError::{DuplicatedLabel, DanglingLabel, Cycle, PresetTable};
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum LabeledDrilldownTreeError {
+    /// Same label is used by more than one drilldowns.
+    DuplicatedLabel(String),
+    /// A drilldown refers a parent label which does not exist.
+    /// It holds the drilldown label and the parent label.
+    DanglingLabel(String, String),
+    /// Drilldowns refer each other. It holds labels which cannot be reached from roots.
+    Cycle(Vec<String>),
+    /// A drilldown in the tree already has `table`. It holds the drilldown label.
+    /// Use `child` to drill down another drilldown instead.
+    PresetTable(String),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LabeledDrilldownTree {
+    nodes: Vec<(Option<String>, LabeledDrilldown)>,
+}
+
+impl Default for LabeledDrilldownTree {
+    fn default() -> LabeledDrilldownTree {
+        LabeledDrilldownTree { nodes: vec![] }
+    }
+}
+
+/// Tree shaped "drilldown in drilldown" builder.
+///
+/// A child drilldown drills down its parent drilldown's result.
+/// `table` of each child is assigned from its parent label when it is flattened,
+/// so drilldowns in the tree must not specify `table` by themselves.
+///
+/// ## Simple usage
+///
+/// ```
+/// use ruroonga_command::selectable::labeled_drilldown::LabeledDrilldown;
+/// use ruroonga_command::selectable::labeled_drilldown_tree::LabeledDrilldownTree;
+/// let tag = LabeledDrilldown::new("tag".to_string())
+///               .keys(vec![("tag".to_string())]);
+/// let category = LabeledDrilldown::new("category".to_string())
+///                    .keys(vec![("category".to_string())]);
+/// let tree = LabeledDrilldownTree::new()
+///                .root(tag)
+///                .child("tag".to_string(), category);
+/// let drilldowns = tree.flatten().unwrap();
+/// assert_eq!(Some("tag"), drilldowns[1].pseudo_table());
+/// ```
+impl LabeledDrilldownTree {
+    pub fn new() -> LabeledDrilldownTree {
+        Default::default()
+    }
+
+    /// Adds a drilldown against `select` result.
+    pub fn root(mut self, drilldown: LabeledDrilldown) -> LabeledDrilldownTree {
+        self.nodes.push((None, drilldown));
+        self
+    }
+
+    /// Adds a drilldown against the result of the drilldown labeled `parent`.
+    pub fn child(mut self, parent: String, drilldown: LabeledDrilldown) -> LabeledDrilldownTree {
+        self.nodes.push((Some(parent), drilldown));
+        self
+    }
+
+    /// Flattens into labeled drilldowns. Parents are placed before their children.
+    ///
+    /// Returns `PresetTable` when a drilldown already has `table`.
+    pub fn flatten(self) -> Result<Vec<LabeledDrilldown>, LabeledDrilldownTreeError> {
+        let mut labels: HashMap<String, usize> = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let drilldown = &node.1;
+            if drilldown.pseudo_table().is_some() {
+                return Err(PresetTable(drilldown.label().to_owned()));
+            }
+            if labels.insert(drilldown.label().to_owned(), index).is_some() {
+                return Err(DuplicatedLabel(drilldown.label().to_owned()));
+            }
+        }
+        let mut children: Vec<Vec<usize>> = vec![vec![]; self.nodes.len()];
+        let mut roots: Vec<usize> = vec![];
+        for (index, node) in self.nodes.iter().enumerate() {
+            let (ref parent, ref drilldown) = *node;
+            match *parent {
+                Some(ref parent) => {
+                    match labels.get(parent) {
+                        Some(&p) => children[p].push(index),
+                        None => {
+                            return Err(DanglingLabel(drilldown.label().to_owned(),
+                                                     parent.to_owned()))
+                        }
+                    }
+                }
+                None => roots.push(index),
+            }
+        }
+        let mut order: Vec<usize> = vec![];
+        let mut stack: Vec<usize> = roots.into_iter().rev().collect();
+        while let Some(index) = stack.pop() {
+            order.push(index);
+            for &child in children[index].iter().rev() {
+                stack.push(child);
+            }
+        }
+        if order.len() < self.nodes.len() {
+            let unreachable = self.nodes
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| !order.contains(&i))
+                .map(|(_, &(_, ref d))| d.label().to_owned())
+                .collect();
+            return Err(Cycle(unreachable));
+        }
+        let mut nodes: Vec<Option<(Option<String>, LabeledDrilldown)>> =
+            self.nodes.into_iter().map(Some).collect();
+        let mut drilldowns = vec![];
+        for index in order {
+            if let Some((parent, drilldown)) = nodes[index].take() {
+                drilldowns.push(match parent {
+                    Some(parent) => drilldown.table(parent),
+                    None => drilldown,
+                });
+            }
+        }
+        Ok(drilldowns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use selectable::labeled_drilldown::LabeledDrilldown;
+    use selectable::pseudo_table::PseudoTable;
+
+    fn labels(drilldowns: &[LabeledDrilldown]) -> Vec<String> {
+        drilldowns.iter().map(|d| d.label().to_owned()).collect()
+    }
+
+    #[test]
+    fn test_flatten() {
+        let tree = LabeledDrilldownTree::new()
+            .root(LabeledDrilldown::new("tag".to_string()))
+            .child("category".to_string(),
+                   LabeledDrilldown::new("sub_category".to_string()))
+            .child("tag".to_string(), LabeledDrilldown::new("category".to_string()))
+            .root(LabeledDrilldown::new("author".to_string()));
+        let drilldowns = tree.flatten().unwrap();
+        assert_eq!(vec!["tag".to_string(),
+                        "category".to_string(),
+                        "sub_category".to_string(),
+                        "author".to_string()],
+                   labels(&drilldowns));
+        assert_eq!(None, drilldowns[0].pseudo_table());
+        assert_eq!(Some("tag"), drilldowns[1].pseudo_table());
+        assert_eq!(Some("category"), drilldowns[2].pseudo_table());
+    }
+
+    #[test]
+    fn test_duplicated_label() {
+        let tree = LabeledDrilldownTree::new()
+            .root(LabeledDrilldown::new("tag".to_string()))
+            .child("tag".to_string(), LabeledDrilldown::new("tag".to_string()));
+        assert_eq!(Some(DuplicatedLabel("tag".to_string())),
+                   tree.flatten().err());
+    }
+
+    #[test]
+    fn test_dangling_label() {
+        let tree = LabeledDrilldownTree::new()
+            .root(LabeledDrilldown::new("tag".to_string()))
+            .child("missing".to_string(),
+                   LabeledDrilldown::new("category".to_string()));
+        assert_eq!(Some(DanglingLabel("category".to_string(), "missing".to_string())),
+                   tree.flatten().err());
+    }
+
+    #[test]
+    fn test_cycle() {
+        let tree = LabeledDrilldownTree::new()
+            .child("category".to_string(), LabeledDrilldown::new("tag".to_string()))
+            .child("tag".to_string(), LabeledDrilldown::new("category".to_string()));
+        assert_eq!(Some(Cycle(vec!["tag".to_string(), "category".to_string()])),
+                   tree.flatten().err());
+    }
+
+    #[test]
+    fn test_root_with_preset_table() {
+        let tree = LabeledDrilldownTree::new()
+            .root(LabeledDrilldown::new("category".to_string()))
+            .root(LabeledDrilldown::new("tag".to_string()).table("category".to_string()));
+        assert_eq!(Some(PresetTable("tag".to_string())), tree.flatten().err());
+    }
+
+    #[test]
+    fn test_child_with_preset_table() {
+        let tree = LabeledDrilldownTree::new()
+            .root(LabeledDrilldown::new("tag".to_string()))
+            .root(LabeledDrilldown::new("author".to_string()))
+            .child("tag".to_string(),
+                   LabeledDrilldown::new("category".to_string()).table("author".to_string()));
+        assert_eq!(Some(PresetTable("category".to_string())), tree.flatten().err());
+    }
+}
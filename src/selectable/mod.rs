@@ -13,6 +13,8 @@ pub mod labeled_drilldown_sequencable;
 pub mod drilldown_builder;
 pub mod labeled_drilldown_builder;
 pub mod labeled_drilldown_sequence_builder;
+/// `labeled_drilldown_tree` provides tree shaped "drilldown in drilldown" construction.
+pub mod labeled_drilldown_tree;
 /// `fragmentable` module provides making query fragment operation.
 /// It is useful to make composable query elements.
 /// It makes `select` query to be composable against drilldown related query
@@ -25,3 +27,7 @@ pub mod pseudo_table;
 pub mod staged_columns;
 pub mod staged_columns_builder;
 pub mod staged_columnable;
+/// `staged_columns_sequence_builder` orders multiple `staged_columns`
+/// with their stages and references.
+pub mod staged_columns_sequence_builder;
+pub mod staged_columns_sequencable;
@@ -6,8 +6,9 @@ use command::{Command, Query};
 use util;
 use selectable::fragmentable::Fragmentable;
 use selectable::fragmentable::{OrderedFragment, QueryFragment};
-use types::data_type::DataType;
+use types::column_type::ColumnType;
 use types::column_flag_type::ColumnFlagType;
+use types::stage_type::StageType;
 use self::WindowFunction::{RecordNumber, ExtWindowFunction};
 use self::WindowableColumn::{Window, Value};
 
@@ -62,25 +63,36 @@ impl fmt::Display for WindowFunction {
     }
 }
 
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum StagedColumnsError {
+    /// Window function is used in the stage which does not support it.
+    WindowFunctionUnavailable(StageType),
+    /// The stage is not supported where the columns are added.
+    StageUnavailable(StageType),
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct StagedColumns {
     label: String,
-    stage: String,
-    column_type: DataType,
+    stage: StageType,
+    column_type: ColumnType,
     value: WindowableColumn<WindowFunction, String>,
     arguments: HashMap<String, String>,
 }
 
 impl StagedColumns {
-    pub fn new(label: String,
-               stage: String,
-               column_type: DataType,
-               value: WindowableColumn<WindowFunction, String>)
-               -> StagedColumns {
+    /// `column_type` accepts `DataType` and `ColumnType`.
+    pub fn new<T>(label: String,
+                  stage: StageType,
+                  column_type: T,
+                  value: WindowableColumn<WindowFunction, String>)
+                  -> StagedColumns
+        where T: Into<ColumnType>
+    {
         StagedColumns {
             label: label,
             stage: stage,
-            column_type: column_type,
+            column_type: column_type.into(),
             value: value,
             arguments: HashMap::new(),
         }
@@ -103,6 +115,50 @@ impl StagedColumns {
         self
     }
 
+    pub fn label(&self) -> &str {
+        self.label.as_ref()
+    }
+
+    pub fn stage(&self) -> &StageType {
+        &self.stage
+    }
+
+    /// Checks whether this column's features are available in its stage.
+    pub fn validate(&self) -> Result<(), StagedColumnsError> {
+        match self.value {
+            Window(_) if !self.stage.is_windowable() => {
+                Err(StagedColumnsError::WindowFunctionUnavailable(self.stage.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Identifiers which are referred from `value` and `sort_keys`.
+    /// Literals which are quoted with `"` are not treated as identifiers.
+    pub fn references(&self) -> Vec<String> {
+        let mut expressions = vec![match self.value {
+                                       Window(ref w) => w.as_ref().to_owned(),
+                                       Value(ref v) => v.to_owned(),
+                                   }];
+        let sort_keys_keys =
+            vec![util::labeled_window_key(self.label.to_owned(), "sort_keys".to_string()),
+                 util::labeled_staged_key(self.label.to_owned(), "sort_keys".to_string())];
+        for key in &sort_keys_keys {
+            if let Some(sort_keys) = self.arguments.get(key) {
+                expressions.push(sort_keys.to_owned());
+            }
+        }
+        let mut references: Vec<String> = vec![];
+        for expression in expressions {
+            for identifier in util::split_identifiers(expression) {
+                if !references.contains(&identifier) {
+                    references.push(identifier);
+                }
+            }
+        }
+        references
+    }
+
     pub fn construct(mut self) -> StagedColumns {
         let stage_key = util::labeled_staged_key(self.label.to_owned(), "stage".to_string());
         let column_type_key = util::labeled_staged_key(self.label.to_owned(), "type".to_string());
@@ -111,8 +167,15 @@ impl StagedColumns {
             Window(w) => w.as_ref().to_owned(),
             Value(v) => v,
         };
-        self.arguments.insert(stage_key, self.stage.clone());
+        self.arguments.insert(stage_key, format!("{}", self.stage));
         self.arguments.insert(column_type_key, format!("{}", self.column_type));
+        if self.column_type.is_vector() {
+            let flags_key = util::labeled_staged_key(self.label.to_owned(), "flags".to_string());
+            if !self.arguments.contains_key(&flags_key) {
+                let flags = format!("\'{}\'", util::split_flags_vec(self.column_type.flags()));
+                self.arguments.insert(flags_key, flags);
+            }
+        }
         self.arguments.insert(value_key, value_str.clone());
         self
     }
@@ -141,8 +204,10 @@ mod test {
     use std::str::FromStr;
     use std::collections::HashMap;
     use types::data_type::DataType;
+    use types::column_type::ColumnType;
     use types::column_flag_type::ColumnFlagType;
-    use super::WindowableColumn::Value;
+    use types::stage_type::StageType;
+    use super::WindowableColumn::{Window, Value};
 
     #[test]
     fn test_as_str() {
@@ -178,7 +243,7 @@ mod test {
     #[test]
     fn test_new() {
         let label = "label1".to_string();
-        let stage = "staged".to_string();
+        let stage = StageType::Filtered;
         let value = "'_id'".to_string();
         let staged_columns = StagedColumns::new(label.clone(),
                                                 stage.clone(),
@@ -191,7 +256,7 @@ mod test {
         let expected = StagedColumns {
             label: label.to_owned(),
             stage: stage.clone(),
-            column_type: DataType::UInt32,
+            column_type: ColumnType::Builtin(DataType::UInt32),
             value: Value(value.clone()),
             arguments: arg,
         };
@@ -201,7 +266,7 @@ mod test {
     #[test]
     fn test_construct() {
         let label = "label1".to_string();
-        let stage = "staged".to_string();
+        let stage = StageType::Filtered;
         let value = "'_id'".to_string();
         let staged_columns = StagedColumns::new(label.clone(),
                                                 stage.clone(),
@@ -215,7 +280,7 @@ mod test {
         assert_eq!(staged_columns.arguments
                        .get(&format!("columns[{}].stage", label.to_owned()))
                        .unwrap(),
-                   &"staged");
+                   &"filtered");
         assert_eq!(staged_columns.arguments
                        .get(&format!("columns[{}].type", label.to_owned()))
                        .unwrap(),
@@ -229,4 +294,47 @@ mod test {
                        .unwrap(),
                    &"\'_id\'");
     }
+
+    #[test]
+    fn test_construct_with_reference_vector() {
+        let tags = ColumnType::ReferenceVector {
+            table: "Tags".to_string(),
+            with_weight: false,
+        };
+        let staged_columns = StagedColumns::new("all_tags".to_string(),
+                                                StageType::Initial,
+                                                tags,
+                                                Value("'tags'".to_string()))
+            .construct();
+        assert_eq!(staged_columns.arguments.get("columns[all_tags].type").unwrap(),
+                   &"Tags");
+        assert_eq!(staged_columns.arguments.get("columns[all_tags].flags").unwrap(),
+                   &"\'COLUMN_VECTOR\'");
+    }
+
+    #[test]
+    fn test_references() {
+        let staged_columns = StagedColumns::new("tax_included".to_string(),
+                                                StageType::Output,
+                                                DataType::UInt32,
+                                                Value("'price * 1.08 + \"fee\"'".to_string()))
+            .sort_keys(vec![("-discounted".to_string())]);
+        assert_eq!(vec!["price".to_string(), "discounted".to_string()],
+                   staged_columns.references());
+    }
+
+    #[test]
+    fn test_validate() {
+        let initial = StagedColumns::new("nth_record".to_string(),
+                                         StageType::Initial,
+                                         DataType::UInt32,
+                                         Window(WindowFunction::RecordNumber));
+        assert_eq!(Ok(()), initial.validate());
+        let output = StagedColumns::new("nth_record".to_string(),
+                                        StageType::Output,
+                                        DataType::UInt32,
+                                        Window(WindowFunction::RecordNumber));
+        assert_eq!(Err(StagedColumnsError::WindowFunctionUnavailable(StageType::Output)),
+                   output.validate());
+    }
 }
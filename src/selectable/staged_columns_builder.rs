@@ -1,4 +1,4 @@
-use selectable::staged_columns::StagedColumns;
+use selectable::staged_columns::{StagedColumns, StagedColumnsError};
 use command::{Command, Query};
 use selectable::fragmentable::Fragmentable;
 use command_query::CommandQuery;
@@ -7,6 +7,7 @@ use commandable::Commandable;
 use command_line::CommandLine;
 use selectable::drilldown_type::DrilldownUsable;
 
+#[derive(Clone)]
 pub struct StagedColumnsBuilder {
     select: DrilldownUsable,
     staged_columns: StagedColumns,
@@ -22,10 +23,11 @@ pub struct StagedColumnsBuilder {
 /// use ruroonga_command::selectable::staged_columns_builder::StagedColumnsBuilder;
 /// use ruroonga_command::types::data_type::DataType;
 /// use ruroonga_command::types::column_flag_type::ColumnFlagType;
+/// use ruroonga_command::types::stage_type::StageType;
 /// use ruroonga_command::selectable::staged_columns::WindowableColumn::Value;
 /// use ruroonga_command::selectable::drilldown_type::DrilldownUsable;
 /// let label = "label1".to_string();
-/// let stage = "staged".to_string();
+/// let stage = StageType::Filtered;
 /// let value = "'_id'".to_string();
 /// let select = SelectCommand::new("Entries".to_string())
 ///                  .filter("content @ \"fast\"".to_string());
@@ -34,7 +36,8 @@ pub struct StagedColumnsBuilder {
 ///                                         DataType::UInt32,
 ///                                         Value(value.clone()))
 ///                                    .flags(vec![(ColumnFlagType::Scalar)]);
-/// let builder = StagedColumnsBuilder::new(DrilldownUsable::Select(select), staged_columns);
+/// let builder = StagedColumnsBuilder::new(DrilldownUsable::Select(select), staged_columns)
+///                   .unwrap();
 /// let _ = builder.build();
 /// ```
 /// In more practical example, please refer to `drilldown_builder` examples
@@ -42,11 +45,15 @@ pub struct StagedColumnsBuilder {
 /// (https://github.com/cosmo0920/ruroonga_command/blob/master/examples/staged_columns_builder.rs).
 
 impl StagedColumnsBuilder {
-    pub fn new(select: DrilldownUsable, staged_columns: StagedColumns) -> StagedColumnsBuilder {
-        StagedColumnsBuilder {
+    /// Returns an error when `staged_columns` uses features which are not available in its stage.
+    pub fn new(select: DrilldownUsable,
+               staged_columns: StagedColumns)
+               -> Result<StagedColumnsBuilder, StagedColumnsError> {
+        try!(staged_columns.validate());
+        Ok(StagedColumnsBuilder {
             select: select,
             staged_columns: staged_columns,
-        }
+        })
     }
 
     pub fn build(self) -> (Command, Query) {
@@ -86,6 +93,8 @@ impl Commandable for StagedColumnsBuilder {
     }
 }
 
+version_checkable!(StagedColumnsBuilder);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -94,16 +103,53 @@ mod test {
     use select::SelectCommand;
     use types::data_type::DataType;
     use types::column_flag_type::ColumnFlagType;
-    use selectable::staged_columns::StagedColumns;
-    use selectable::staged_columns::WindowableColumn::Value;
+    use types::stage_type::StageType;
+    use selectable::staged_columns::{StagedColumns, StagedColumnsError, WindowFunction};
+    use selectable::staged_columns::WindowableColumn::{Value, Window};
     use selectable::drilldown_type::DrilldownUsable;
+    use types::groonga_version::GroongaVersion;
+    use version_checkable::{VersionCheckable, VersionCheckError};
+
+    #[test]
+    fn test_check_version() {
+        let select = SelectCommand::new("Entries".to_string());
+        let staged_columns = StagedColumns::new("label1".to_string(),
+                                                StageType::Filtered,
+                                                DataType::UInt32,
+                                                Value("'_id'".to_string()));
+        let builder = StagedColumnsBuilder::new(DrilldownUsable::Select(select), staged_columns)
+            .unwrap();
+        assert_eq!(Ok(()), builder.check_version(&GroongaVersion::new(7, 0, 0)));
+        match builder.check_version(&GroongaVersion::new(6, 0, 3)) {
+            Err(VersionCheckError::UnsupportedParameter(parameter, since)) => {
+                assert!(parameter.starts_with("columns[label1]."));
+                assert_eq!(GroongaVersion::new(6, 0, 5), since);
+            }
+            _ => panic!("Staged columns must be rejected by Groonga 6.0.3!"),
+        }
+    }
+
+    #[test]
+    fn test_new_with_window_function_in_output_stage() {
+        let select = SelectCommand::new("Entries".to_string());
+        let staged_columns = StagedColumns::new("nth_record".to_string(),
+                                                StageType::Output,
+                                                DataType::UInt32,
+                                                Window(WindowFunction::RecordNumber));
+        match StagedColumnsBuilder::new(DrilldownUsable::Select(select), staged_columns) {
+            Err(e) => {
+                assert_eq!(StagedColumnsError::WindowFunctionUnavailable(StageType::Output), e)
+            }
+            Ok(_) => panic!("Window function must be rejected in output stage!"),
+        }
+    }
 
     #[test]
     #[ignore]
     // Too complex test
     fn test_to_query() {
         let label = "label1".to_string();
-        let stage = "staged".to_string();
+        let stage = StageType::Filtered;
         let value = "'_id'".to_string();
         let select = SelectCommand::new("Entries".to_string())
             .filter("content @ \"fast\"".to_string());
@@ -113,10 +159,11 @@ mod test {
                                                 Value(value.clone()))
             .flags(vec![(ColumnFlagType::Scalar)]);
         let builder = StagedColumnsBuilder::new(DrilldownUsable::Select(select), staged_columns)
+            .unwrap()
             .to_query();
         let encoded = "/d/select?table=Entries&filter=%27content+%40+%22fast%22%27&columns%5Blabel\
                        1%5D.value=%27_id%27&columns%5Blabel1%5D.type=UInt32&columns%5Blabel1%5D.\
-                       flags=%27COLUMN_SCALAR%27&columns%5Blabel1%5D.stage=staged"
+                       flags=%27COLUMN_SCALAR%27&columns%5Blabel1%5D.stage=filtered"
             .to_string();
         assert_eq!(encoded, builder);
     }
@@ -126,7 +173,7 @@ mod test {
     // Too complex test
     fn test_to_command() {
         let label = "label1".to_string();
-        let stage = "staged".to_string();
+        let stage = StageType::Filtered;
         let value = "'_id'".to_string();
         let select = SelectCommand::new("Entries".to_string())
             .filter("content @ \"fast\"".to_string());
@@ -136,9 +183,10 @@ mod test {
                                                 Value(value.clone()))
             .flags(vec![(ColumnFlagType::Scalar)]);
         let builder = StagedColumnsBuilder::new(DrilldownUsable::Select(select), staged_columns)
+            .unwrap()
             .to_command();
         let encoded = "select --table Entries --filter \'content @ \"fast\"\' \
-                       --columns[label1].value \'_id\' --columns[label1].stage staged \
+                       --columns[label1].value \'_id\' --columns[label1].stage filtered \
                        --columns[label1].flags \'COLUMN_SCALAR\' --columns[label1].type UInt32"
             .to_string();
         assert_eq!(encoded, builder);
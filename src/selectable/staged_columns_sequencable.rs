@@ -0,0 +1,10 @@
+use selectable::staged_columns::StagedColumns;
+use selectable::staged_columns_sequence_builder::{StagedColumnsSequenceBuilder,
+                                                  StagedColumnsSequenceError};
+
+pub trait StagedColumnsSequencable {
+    fn with_staged_columns_sequence
+        (self,
+         rhs: Vec<StagedColumns>)
+         -> Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError>;
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use selectable::staged_columns::{StagedColumns, StagedColumnsError};
+use command::{Command, Query};
+use selectable::fragmentable::Fragmentable;
+use command_query::CommandQuery;
+use queryable::Queryable;
+use commandable::Commandable;
+use command_line::CommandLine;
+use selectable::drilldown_type::DrilldownUsable;
+use types::stage_type::StageType;
+use self::StagedColumnsSequenceError::{DuplicatedLabel, InvalidStagedColumns, StageMismatch,
+                                      Cycle};
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum StagedColumnsSequenceError {
+    /// Same label is used by more than one staged columns.
+    DuplicatedLabel(String),
+    /// A staged column uses features which are not available in its stage.
+    InvalidStagedColumns(String, StagedColumnsError),
+    /// A staged column refers another one which is calculated in later stage.
+    /// It holds the referring label and the referred label.
+    StageMismatch(String, String),
+    /// Staged columns refer each other. It holds labels which cannot be ordered.
+    Cycle(Vec<String>),
+}
+
+#[derive(Clone)]
+pub struct StagedColumnsSequenceBuilder {
+    select: DrilldownUsable,
+    staged_columns: Vec<StagedColumns>,
+}
+
+/// `StagedColumnsSequence` builder.
+///
+/// Staged columns are ordered by their stage (`initial`, `filtered` and `output`)
+/// and by references between them.
+/// A reference is a label which appears in other column's `value` or `sort_keys`.
+///
+/// ## Simple usage
+///
+/// ```
+/// use ruroonga_command::select::SelectCommand;
+/// use ruroonga_command::selectable::staged_columns::StagedColumns;
+/// use ruroonga_command::selectable::staged_columns_sequence_builder as builder;
+/// use ruroonga_command::types::data_type::DataType;
+/// use ruroonga_command::types::stage_type::StageType;
+/// use ruroonga_command::selectable::staged_columns::WindowableColumn::Value;
+/// use ruroonga_command::selectable::drilldown_type::DrilldownUsable;
+/// let select = SelectCommand::new("Items".to_string());
+/// let tax_included = StagedColumns::new("tax_included".to_string(),
+///                                       StageType::Output,
+///                                       DataType::UInt32,
+///                                       Value("'discounted * 1.08'".to_string()));
+/// let discounted = StagedColumns::new("discounted".to_string(),
+///                                     StageType::Initial,
+///                                     DataType::UInt32,
+///                                     Value("'price * 0.9'".to_string()));
+/// let builder =
+///     builder::StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select),
+///                                                vec![(tax_included), (discounted)])
+///         .unwrap();
+/// let _ = builder.build();
+/// ```
+impl StagedColumnsSequenceBuilder {
+    pub fn new(select: DrilldownUsable,
+               staged_columns: Vec<StagedColumns>)
+               -> Result<StagedColumnsSequenceBuilder, StagedColumnsSequenceError> {
+        let ordered = try!(dependency_order(staged_columns));
+        Ok(StagedColumnsSequenceBuilder {
+            select: select,
+            staged_columns: ordered,
+        })
+    }
+
+    pub fn build(self) -> (Command, Query) {
+        let mut query: Query = vec![];
+        let (command, ordered_select, key_values) = match self.select {
+            DrilldownUsable::Select(s) => s.to_fragment(),
+            #[cfg(feature="sharding")]
+            DrilldownUsable::LogicalSelect(l) => l.to_fragment(),
+        };
+        for ordered in &ordered_select {
+            query.push(ordered.to_owned());
+        }
+        for (key, value) in &key_values {
+            query.push((key.to_owned(), value.to_owned()));
+        }
+        for staged_columns in &self.staged_columns {
+            let (_, _, staged_columns) = staged_columns.to_owned().construct().to_fragment();
+            for (key, value) in &staged_columns {
+                query.push((key.to_owned(), value.to_owned()));
+            }
+        }
+        (command, query)
+    }
+}
+
+fn stage_order(stage: &StageType) -> usize {
+    match *stage {
+        StageType::Initial => 0,
+        StageType::Filtered => 1,
+        StageType::Output => 2,
+        StageType::ExtStageType(_) => 3,
+    }
+}
+
+// Self reference is not a dependency.
+// It refers the same name column in the target table.
+fn dependency_order(staged_columns: Vec<StagedColumns>)
+                    -> Result<Vec<StagedColumns>, StagedColumnsSequenceError> {
+    let mut labels: HashMap<String, usize> = HashMap::new();
+    for (index, column) in staged_columns.iter().enumerate() {
+        if labels.insert(column.label().to_owned(), index).is_some() {
+            return Err(DuplicatedLabel(column.label().to_owned()));
+        }
+        if let Err(e) = column.validate() {
+            return Err(InvalidStagedColumns(column.label().to_owned(), e));
+        }
+    }
+    let mut dependencies: Vec<Vec<usize>> = vec![];
+    for column in &staged_columns {
+        let mut depends = vec![];
+        for reference in column.references() {
+            if reference == column.label() {
+                continue;
+            }
+            if let Some(&index) = labels.get(&reference) {
+                if stage_order(staged_columns[index].stage()) > stage_order(column.stage()) {
+                    return Err(StageMismatch(column.label().to_owned(), reference));
+                }
+                depends.push(index);
+            }
+        }
+        dependencies.push(depends);
+    }
+    let mut resolved = vec![false; staged_columns.len()];
+    let mut order: Vec<usize> = vec![];
+    while order.len() < staged_columns.len() {
+        let next = (0..staged_columns.len())
+            .filter(|&i| !resolved[i] && dependencies[i].iter().all(|&d| resolved[d]))
+            .min_by_key(|&i| (stage_order(staged_columns[i].stage()), i));
+        match next {
+            Some(i) => {
+                resolved[i] = true;
+                order.push(i);
+            }
+            None => {
+                let unresolved = staged_columns.iter()
+                    .enumerate()
+                    .filter(|&(i, _)| !resolved[i])
+                    .map(|(_, c)| c.label().to_owned())
+                    .collect();
+                return Err(Cycle(unresolved));
+            }
+        }
+    }
+    let mut columns: Vec<Option<StagedColumns>> = staged_columns.into_iter().map(Some).collect();
+    Ok(order.into_iter().filter_map(|i| columns[i].take()).collect())
+}
+
+impl Queryable for StagedColumnsSequenceBuilder {
+    fn to_query(self) -> String {
+        let (command, query) = self.build();
+        let mut command = CommandQuery::new(command, query);
+        command.encode()
+    }
+}
+
+impl Commandable for StagedColumnsSequenceBuilder {
+    fn to_command(self) -> String {
+        let (command, query) = self.build();
+        let mut command = CommandLine::new(command, query);
+        command.encode()
+    }
+}
+
+version_checkable!(StagedColumnsSequenceBuilder);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use commandable::Commandable;
+    use select::SelectCommand;
+    use types::data_type::DataType;
+    use types::stage_type::StageType;
+    use selectable::staged_columns::{StagedColumns, StagedColumnsError, WindowFunction};
+    use selectable::staged_columns::WindowableColumn::{Window, Value};
+    use selectable::drilldown_type::DrilldownUsable;
+
+    fn column(label: &str, stage: StageType, value: &str) -> StagedColumns {
+        StagedColumns::new(label.to_string(),
+                           stage,
+                           DataType::UInt32,
+                           Value(value.to_string()))
+    }
+
+    fn labels(builder: &StagedColumnsSequenceBuilder) -> Vec<String> {
+        builder.staged_columns.iter().map(|c| c.label().to_owned()).collect()
+    }
+
+    #[test]
+    fn test_dependency_order() {
+        let select = SelectCommand::new("Items".to_string());
+        let columns = vec![(column("total", StageType::Output, "'tax + discounted'")),
+                           (column("tax", StageType::Filtered, "'discounted * 0.08'")),
+                           (column("discounted", StageType::Filtered, "'price * 0.9'"))];
+        let builder = StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select), columns)
+            .unwrap();
+        assert_eq!(vec!["discounted".to_string(), "tax".to_string(), "total".to_string()],
+                   labels(&builder));
+    }
+
+    #[test]
+    fn test_duplicated_label() {
+        let select = SelectCommand::new("Items".to_string());
+        let columns = vec![(column("price", StageType::Initial, "'_id'")),
+                           (column("price", StageType::Output, "'_id'"))];
+        let result = StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select), columns);
+        assert_eq!(Some(DuplicatedLabel("price".to_string())), result.err());
+    }
+
+    #[test]
+    fn test_cycle() {
+        let select = SelectCommand::new("Items".to_string());
+        let columns = vec![(column("a", StageType::Initial, "'b + 1'")),
+                           (column("b", StageType::Initial, "'a + 1'")),
+                           (column("c", StageType::Initial, "'_id'"))];
+        let result = StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select), columns);
+        assert_eq!(Some(Cycle(vec!["a".to_string(), "b".to_string()])),
+                   result.err());
+    }
+
+    #[test]
+    fn test_stage_mismatch() {
+        let select = SelectCommand::new("Items".to_string());
+        let columns = vec![(column("a", StageType::Initial, "'b + 1'")),
+                           (column("b", StageType::Output, "'_id'"))];
+        let result = StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select), columns);
+        assert_eq!(Some(StageMismatch("a".to_string(), "b".to_string())),
+                   result.err());
+    }
+
+    #[test]
+    fn test_invalid_staged_columns() {
+        let select = SelectCommand::new("Items".to_string());
+        let window = StagedColumns::new("nth_record".to_string(),
+                                        StageType::Output,
+                                        DataType::UInt32,
+                                        Window(WindowFunction::RecordNumber));
+        let result = StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select),
+                                                       vec![(window)]);
+        let expected =
+            InvalidStagedColumns("nth_record".to_string(),
+                                 StagedColumnsError::WindowFunctionUnavailable(StageType::Output));
+        assert_eq!(Some(expected), result.err());
+    }
+
+    #[test]
+    fn test_to_command() {
+        let select = SelectCommand::new("Items".to_string());
+        let columns = vec![(column("price", StageType::Initial, "'_id'"))];
+        let builder = StagedColumnsSequenceBuilder::new(DrilldownUsable::Select(select), columns)
+            .unwrap()
+            .to_command();
+        assert!(builder.starts_with("select --table Items"));
+        assert!(builder.contains("--columns[price].stage initial"));
+        assert!(builder.contains("--columns[price].value \'_id\'"));
+    }
+}
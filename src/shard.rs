@@ -0,0 +1,463 @@
+use table_create::TableCreateCommand;
+use table_remove::TableRemoveCommand;
+use column_create::ColumnCreateCommand;
+use logical_table_remove::LogicalTableRemoveCommand;
+use types::data_type::DataType;
+use types::column_type::ColumnType;
+use types::table_flag_type::TableFlagType;
+use types::column_flag_type::ColumnFlagType;
+use types::groonga_time::GroongaTime;
+use types::time_range::TimeRange;
+use types::range_filter_type::RangeFilterType;
+
+/// Unit of shards of a logical table.
+#[derive (Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShardGranularity {
+    /// Shards are named like `Logs_20161018`.
+    Day,
+    /// Shards are named like `Logs_201610`.
+    Month,
+}
+
+fn shift(time: GroongaTime, seconds: i64) -> GroongaTime {
+    GroongaTime::from_microseconds(time.microseconds() + seconds * 1000000)
+}
+
+// Converts months since 0000-01 into (year, month).
+fn year_month(index: i64) -> (i64, u32) {
+    let year = if index >= 0 { index } else { index - 11 } / 12;
+    (year, (index - year * 12 + 1) as u32)
+}
+
+// The number of days in the month. `month` must be in 1 to 12.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    let next = if month == 12 {
+        GroongaTime::from_date(year + 1, 1, 1)
+    } else {
+        GroongaTime::from_date(year, month + 1, 1)
+    };
+    (next.days() - GroongaTime::from_date(year, month, 1).days()) as u32
+}
+
+/// Names shards of a logical table and plans commands against them.
+///
+/// Groonga names shards by dates in its local time zone.
+/// Set `utc_offset` when the server does not run in UTC.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::shard::{ShardPlanner, ShardGranularity};
+/// use ruroonga_command::types::groonga_time::GroongaTime;
+/// use ruroonga_command::types::time_range::TimeRange;
+/// use ruroonga_command::types::range_filter_type::RangeFilterType;
+/// let planner = ShardPlanner::new("Logs".to_string(), ShardGranularity::Day);
+/// let range = TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+///                            GroongaTime::from_date(2016, 10, 20))
+///                 .max_border(RangeFilterType::Exclude);
+/// let shards = planner.shards(&range);
+/// assert_eq!(vec!["Logs_20161018".to_string(), "Logs_20161019".to_string()], shards);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShardPlanner {
+    logical_table: String,
+    granularity: ShardGranularity,
+    utc_offset: i64,
+}
+
+impl ShardPlanner {
+    pub fn new(logical_table: String, granularity: ShardGranularity) -> ShardPlanner {
+        ShardPlanner {
+            logical_table: logical_table,
+            granularity: granularity,
+            utc_offset: 0,
+        }
+    }
+
+    /// Offset of the server's local time from UTC in seconds. It is `0` by default.
+    ///
+    /// For example, it is `32400` for `+09:00`.
+    pub fn utc_offset(mut self, seconds: i64) -> ShardPlanner {
+        self.utc_offset = seconds;
+        self
+    }
+
+    // Index of the shard: days or months since the epoch in the local time.
+    fn shard_index(&self, time: GroongaTime) -> i64 {
+        let time = shift(time, self.utc_offset);
+        match self.granularity {
+            ShardGranularity::Day => time.days(),
+            ShardGranularity::Month => {
+                let (year, month, _) = time.date();
+                year * 12 + month as i64 - 1
+            }
+        }
+    }
+
+    // The first moment of the shard in the local time.
+    fn local_shard_start(&self, index: i64) -> GroongaTime {
+        match self.granularity {
+            ShardGranularity::Day => GroongaTime::from_seconds(index * 86400),
+            ShardGranularity::Month => {
+                let (year, month) = year_month(index);
+                GroongaTime::from_date(year, month, 1)
+            }
+        }
+    }
+
+    // The first moment of the shard.
+    fn shard_start(&self, index: i64) -> GroongaTime {
+        shift(self.local_shard_start(index), -self.utc_offset)
+    }
+
+    fn shard_name_of_index(&self, index: i64) -> String {
+        let (year, month, day) = self.local_shard_start(index).date();
+        match self.granularity {
+            ShardGranularity::Day => {
+                format!("{}_{:04}{:02}{:02}", self.logical_table, year, month, day)
+            }
+            ShardGranularity::Month => format!("{}_{:04}{:02}", self.logical_table, year, month),
+        }
+    }
+
+    /// The shard name which holds records at `time`.
+    pub fn shard_name(&self, time: GroongaTime) -> String {
+        let index = self.shard_index(time);
+        self.shard_name_of_index(index)
+    }
+
+    /// Enumerates shard names which may hold records in `range`.
+    ///
+    /// The shard starting at `max` is skipped when `max_border` is `Exclude`.
+    pub fn shards(&self, range: &TimeRange) -> Vec<String> {
+        let excluded = range.min_border == RangeFilterType::Exclude ||
+                       range.max_border == RangeFilterType::Exclude;
+        if range.min > range.max || (range.min == range.max && excluded) {
+            return vec![];
+        }
+        let first = self.shard_index(range.min);
+        let mut last = self.shard_index(range.max);
+        if range.max_border == RangeFilterType::Exclude && self.shard_start(last) == range.max {
+            last -= 1;
+        }
+        (first..last + 1).map(|index| self.shard_name_of_index(index)).collect()
+    }
+
+    /// Commands to create the shard which holds records at `time`.
+    ///
+    /// The shard has `shard_key` column as `Time` and `columns`.
+    pub fn create_shard(&self,
+                        time: GroongaTime,
+                        shard_key: String,
+                        columns: Vec<(String, ColumnType)>)
+                        -> (TableCreateCommand, Vec<ColumnCreateCommand>) {
+        let name = self.shard_name(time);
+        let table_create = TableCreateCommand::new(name.clone())
+            .flags(vec![TableFlagType::NoKey]);
+        let mut column_creates = vec![ColumnCreateCommand::new(name.clone(), shard_key)
+                                          .flags(vec![ColumnFlagType::Scalar])
+                                          .column_type(DataType::Time)];
+        for (column, column_type) in columns {
+            column_creates.push(ColumnCreateCommand::new(name.clone(), column)
+                .flags(column_type.flags())
+                .column_type(column_type));
+        }
+        (table_create, column_creates)
+    }
+}
+
+/// A shard which is listed by `logical_shard_list`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Shard {
+    pub name: String,
+    pub logical_table: String,
+    pub granularity: ShardGranularity,
+    /// The first moment of records in this shard.
+    pub start: GroongaTime,
+    /// Offset of the server's local time from UTC in seconds.
+    pub utc_offset: i64,
+}
+
+impl Shard {
+    /// Parses `${LOGICAL_TABLE}_${YYYYMMDD}` or `${LOGICAL_TABLE}_${YYYYMM}` style name.
+    ///
+    /// The date is treated as UTC. Use `parse_with_utc_offset` for other time zones.
+    pub fn parse(name: &str) -> Option<Shard> {
+        Shard::parse_with_utc_offset(name, 0)
+    }
+
+    /// Parses a shard name whose date is in the local time of `utc_offset` seconds.
+    pub fn parse_with_utc_offset(name: &str, utc_offset: i64) -> Option<Shard> {
+        let separator = match name.rfind('_') {
+            Some(separator) if separator > 0 => separator,
+            _ => return None,
+        };
+        let (logical_table, suffix) = (&name[..separator], &name[separator + 1..]);
+        if !suffix.chars().all(|c| "0123456789".contains(c)) {
+            return None;
+        }
+        let (granularity, day) = match suffix.len() {
+            8 => (ShardGranularity::Day, suffix[6..8].parse().unwrap_or(0)),
+            6 => (ShardGranularity::Month, 1),
+            _ => return None,
+        };
+        let year: i64 = suffix[0..4].parse().unwrap_or(0);
+        let month: u32 = suffix[4..6].parse().unwrap_or(0);
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Shard {
+            name: name.to_owned(),
+            logical_table: logical_table.to_owned(),
+            granularity: granularity,
+            start: shift(GroongaTime::from_date(year, month, day), -utc_offset),
+            utc_offset: utc_offset,
+        })
+    }
+
+    /// The first moment of the next shard.
+    pub fn end(&self) -> GroongaTime {
+        match self.granularity {
+            ShardGranularity::Day => GroongaTime::from_seconds(self.start.seconds() + 86400),
+            ShardGranularity::Month => {
+                let (year, month, _) = shift(self.start, self.utc_offset).date();
+                let next = if month == 12 {
+                    GroongaTime::from_date(year + 1, 1, 1)
+                } else {
+                    GroongaTime::from_date(year, month + 1, 1)
+                };
+                shift(next, -self.utc_offset)
+            }
+        }
+    }
+}
+
+/// Computes commands to drop shards which are out of the retention window.
+///
+/// A shard is expired when all of its records are older than the cutoff.
+///
+/// ## Usage
+///
+/// ```
+/// use ruroonga_command::shard::{Shard, ShardRetention};
+/// use ruroonga_command::types::groonga_time::GroongaTime;
+/// let shards: Vec<Shard> = vec!["Logs_20161016", "Logs_20161017", "Logs_20161018"]
+///     .into_iter()
+///     .filter_map(Shard::parse)
+///     .collect();
+/// let retention = ShardRetention::days(GroongaTime::from_date(2016, 10, 19), 2);
+/// let removes = retention.table_removes(&shards);
+/// assert_eq!(1, removes.len());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShardRetention {
+    cutoff: GroongaTime,
+}
+
+impl ShardRetention {
+    /// Records older than `cutoff` are expired.
+    pub fn new(cutoff: GroongaTime) -> ShardRetention {
+        ShardRetention { cutoff: cutoff }
+    }
+
+    /// Keeps records in `days` days before `now`.
+    pub fn days(now: GroongaTime, days: i64) -> ShardRetention {
+        ShardRetention::new(GroongaTime::from_microseconds(now.microseconds() -
+                                                           days * 86400 * 1000000))
+    }
+
+    pub fn is_expired(&self, shard: &Shard) -> bool {
+        shard.end() <= self.cutoff
+    }
+
+    pub fn expired<'a>(&self, shards: &'a [Shard]) -> Vec<&'a Shard> {
+        shards.iter().filter(|shard| self.is_expired(shard)).collect()
+    }
+
+    /// One `logical_table_remove` per logical table which covers only expired shards.
+    pub fn logical_table_removes(&self,
+                                 shard_key: String,
+                                 shards: &[Shard])
+                                 -> Vec<LogicalTableRemoveCommand> {
+        let mut ranges: Vec<(String, GroongaTime, GroongaTime)> = vec![];
+        for shard in self.expired(shards) {
+            match ranges.iter().position(|r| r.0 == shard.logical_table) {
+                Some(i) => {
+                    if shard.start < ranges[i].1 {
+                        ranges[i].1 = shard.start;
+                    }
+                    if shard.end() > ranges[i].2 {
+                        ranges[i].2 = shard.end();
+                    }
+                }
+                None => ranges.push((shard.logical_table.clone(), shard.start, shard.end())),
+            }
+        }
+        ranges.into_iter()
+            .map(|(logical_table, min, max)| {
+                LogicalTableRemoveCommand::new(logical_table, shard_key.clone())
+                    .range(TimeRange::new(min, max).max_border(RangeFilterType::Exclude))
+            })
+            .collect()
+    }
+
+    /// `table_remove` with `dependent` for each expired shard.
+    pub fn table_removes(&self, shards: &[Shard]) -> Vec<TableRemoveCommand> {
+        self.expired(shards)
+            .into_iter()
+            .map(|shard| TableRemoveCommand::new(shard.name.clone()).dependent(true))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use table_create::TableCreateCommand;
+    use table_remove::TableRemoveCommand;
+    use column_create::ColumnCreateCommand;
+    use logical_table_remove::LogicalTableRemoveCommand;
+    use types::data_type::DataType;
+    use types::column_type::ColumnType;
+    use types::table_flag_type::TableFlagType;
+    use types::column_flag_type::ColumnFlagType;
+    use types::groonga_time::GroongaTime;
+    use types::time_range::TimeRange;
+    use types::range_filter_type::RangeFilterType;
+
+    #[test]
+    fn test_year_month() {
+        assert_eq!((2016, 12), year_month(2016 * 12 + 11));
+        assert_eq!((-1, 12), year_month(-1));
+    }
+
+    #[test]
+    fn test_shard_name() {
+        let time = GroongaTime::from_datetime(2016, 10, 18, 12, 34, 56);
+        let day = ShardPlanner::new("Logs".to_string(), ShardGranularity::Day);
+        assert_eq!("Logs_20161018".to_string(), day.shard_name(time));
+        let month = ShardPlanner::new("Logs".to_string(), ShardGranularity::Month);
+        assert_eq!("Logs_201610".to_string(), month.shard_name(time));
+    }
+
+    #[test]
+    fn test_shard_name_with_utc_offset() {
+        let planner = ShardPlanner::new("Logs".to_string(), ShardGranularity::Day)
+            .utc_offset(9 * 3600);
+        let time = GroongaTime::from_datetime(2016, 10, 18, 16, 0, 0);
+        assert_eq!("Logs_20161019".to_string(), planner.shard_name(time));
+        let range = TimeRange::new(GroongaTime::from_datetime(2016, 10, 17, 15, 0, 0),
+                                   GroongaTime::from_datetime(2016, 10, 18, 15, 0, 0))
+            .max_border(RangeFilterType::Exclude);
+        assert_eq!(vec!["Logs_20161018".to_string()], planner.shards(&range));
+    }
+
+    #[test]
+    fn test_shards() {
+        let planner = ShardPlanner::new("Logs".to_string(), ShardGranularity::Day);
+        let time = GroongaTime::from_datetime(2016, 10, 18, 12, 34, 56);
+        let next_day = GroongaTime::from_date(2016, 10, 19);
+        assert_eq!(vec!["Logs_20161018".to_string(), "Logs_20161019".to_string()],
+                   planner.shards(&TimeRange::new(time, next_day)));
+        assert_eq!(vec!["Logs_20161018".to_string()],
+                   planner.shards(&TimeRange::new(time, next_day)
+                       .max_border(RangeFilterType::Exclude)));
+        assert!(planner.shards(&TimeRange::new(next_day, time)).is_empty());
+    }
+
+    #[test]
+    fn test_shards_across_year() {
+        let planner = ShardPlanner::new("Logs".to_string(), ShardGranularity::Month);
+        let range = TimeRange::new(GroongaTime::from_date(2016, 11, 15),
+                                   GroongaTime::from_date(2017, 2, 1))
+            .max_border(RangeFilterType::Exclude);
+        assert_eq!(vec!["Logs_201611".to_string(),
+                        "Logs_201612".to_string(),
+                        "Logs_201701".to_string()],
+                   planner.shards(&range));
+    }
+
+    #[test]
+    fn test_create_shard() {
+        let planner = ShardPlanner::new("Logs".to_string(), ShardGranularity::Day);
+        let (table, columns) = planner.create_shard(GroongaTime::from_date(2016, 10, 18),
+                                                    "timestamp".to_string(),
+                                                    vec![("message".to_string(),
+                                                          ColumnType::from(DataType::Text))]);
+        assert_eq!(TableCreateCommand::new("Logs_20161018".to_string())
+                       .flags(vec![TableFlagType::NoKey]),
+                   table);
+        let expected = vec![ColumnCreateCommand::new("Logs_20161018".to_string(),
+                                                     "timestamp".to_string())
+                                .flags(vec![ColumnFlagType::Scalar])
+                                .column_type(DataType::Time),
+                            ColumnCreateCommand::new("Logs_20161018".to_string(),
+                                                     "message".to_string())
+                                .flags(vec![ColumnFlagType::Scalar])
+                                .column_type(DataType::Text)];
+        assert_eq!(expected, columns);
+    }
+
+    #[test]
+    fn test_parse_shard() {
+        let day = Shard::parse("Access_Logs_20161018").unwrap();
+        assert_eq!("Access_Logs".to_string(), day.logical_table);
+        assert_eq!(ShardGranularity::Day, day.granularity);
+        assert_eq!(GroongaTime::from_date(2016, 10, 18), day.start);
+        assert_eq!(GroongaTime::from_date(2016, 10, 19), day.end());
+        let month = Shard::parse("Logs_201612").unwrap();
+        assert_eq!(ShardGranularity::Month, month.granularity);
+        assert_eq!(GroongaTime::from_date(2017, 1, 1), month.end());
+        assert_eq!(None, Shard::parse("Logs"));
+        assert_eq!(None, Shard::parse("Logs_2016101"));
+        assert_eq!(None, Shard::parse("Logs_20161332"));
+        assert_eq!(None, Shard::parse("Logs_20161000"));
+        assert_eq!(None, Shard::parse("Logs_201610１８"));
+    }
+
+    #[test]
+    fn test_parse_shard_with_month_length() {
+        assert!(Shard::parse("Logs_20161031").is_some());
+        assert_eq!(None, Shard::parse("Logs_20161131"));
+        assert!(Shard::parse("Logs_20160229").is_some());
+        assert_eq!(None, Shard::parse("Logs_20160230"));
+        assert_eq!(None, Shard::parse("Logs_20170229"));
+        assert!(Shard::parse("Logs_20000229").is_some());
+        assert_eq!(None, Shard::parse("Logs_21000229"));
+        assert!(Shard::parse("Logs_20161231").is_some());
+    }
+
+    #[test]
+    fn test_parse_shard_with_utc_offset() {
+        let day = Shard::parse_with_utc_offset("Logs_20161018", 9 * 3600).unwrap();
+        assert_eq!(GroongaTime::from_datetime(2016, 10, 17, 15, 0, 0), day.start);
+        assert_eq!(GroongaTime::from_datetime(2016, 10, 18, 15, 0, 0), day.end());
+        let month = Shard::parse_with_utc_offset("Logs_201612", -5 * 3600).unwrap();
+        assert_eq!(GroongaTime::from_datetime(2016, 12, 1, 5, 0, 0), month.start);
+        assert_eq!(GroongaTime::from_datetime(2017, 1, 1, 5, 0, 0), month.end());
+    }
+
+    #[test]
+    fn test_retention() {
+        let shards: Vec<Shard> = vec!["Logs_20161016", "Logs_20161017", "Logs_20161018"]
+            .into_iter()
+            .filter_map(Shard::parse)
+            .collect();
+        let retention = ShardRetention::days(GroongaTime::from_datetime(2016, 10, 19, 12, 0, 0),
+                                             2);
+        assert_eq!(vec![&shards[0]], retention.expired(&shards));
+        let retention = ShardRetention::new(GroongaTime::from_date(2016, 10, 18));
+        assert_eq!(vec![TableRemoveCommand::new("Logs_20161016".to_string()).dependent(true),
+                        TableRemoveCommand::new("Logs_20161017".to_string()).dependent(true)],
+                   retention.table_removes(&shards));
+        let range = TimeRange::new(GroongaTime::from_date(2016, 10, 16),
+                                   GroongaTime::from_date(2016, 10, 18))
+            .max_border(RangeFilterType::Exclude);
+        assert_eq!(vec![LogicalTableRemoveCommand::new("Logs".to_string(),
+                                                       "timestamp".to_string())
+                            .range(range)],
+                   retention.logical_table_removes("timestamp".to_string(), &shards));
+        let retention = ShardRetention::new(GroongaTime::from_date(2016, 10, 1));
+        assert!(retention.logical_table_removes("timestamp".to_string(), &shards).is_empty());
+    }
+}
@@ -64,6 +64,7 @@ impl Commandable for ShutdownCommand {
 extendable!(ShutdownCommand);
 request_cancellable!(ShutdownCommand);
 request_timeoutable!(ShutdownCommand);
+version_checkable!(ShutdownCommand);
 
 #[cfg(test)]
 mod test {
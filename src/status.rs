@@ -55,6 +55,7 @@ impl Commandable for StatusCommand {
 
 request_cancellable!(StatusCommand);
 request_timeoutable!(StatusCommand);
+version_checkable!(StatusCommand);
 
 #[cfg(test)]
 mod test {
@@ -1,8 +1,9 @@
 use command::{Command, Query};
 use command::Command::TableCreate;
 use std::collections::HashMap;
-use types::table_flag_type::TableFlagType;
+use types::table_flag_type::{self, TableFlagType, TableFlagsError};
 use types::data_type::DataType;
+use types::column_type::ColumnType;
 use types::tokenizer_type::TokenizerType;
 use types::normalizer_type::NormalizerType;
 use types::token_filters_type::TokenFiltersType;
@@ -14,6 +15,9 @@ use commandable::Commandable;
 use extendable::Extendable;
 use request_cancellable::RequestCancellable;
 use request_timeoutable::RequestTimeoutable;
+use plugin_requirable::PluginRequirable;
+use types::plugin::Plugin;
+use std::str::FromStr;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct TableCreateCommand {
@@ -49,6 +53,17 @@ impl TableCreateCommand {
         self
     }
 
+    /// Value type of records. Tables without values do not need it.
+    ///
+    /// Accepts `DataType` and `ColumnType` such as `ColumnType::Reference`.
+    pub fn value_type<T>(mut self, value_type: T) -> TableCreateCommand
+        where T: Into<ColumnType>
+    {
+        let string = format!("{}", value_type.into());
+        self.arguments.insert("value_type".to_string(), string.to_owned());
+        self
+    }
+
     pub fn default_tokenizer(mut self, tokenizer: TokenizerType) -> TableCreateCommand {
         let string = format!("{}", tokenizer);
         self.arguments.insert("default_tokenizer".to_string(), string.to_owned());
@@ -61,12 +76,52 @@ impl TableCreateCommand {
         self
     }
 
-    pub fn token_filter(mut self, token_filter: TokenFiltersType) -> TableCreateCommand {
-        let string = format!("{}", token_filter);
-        self.arguments.insert("token_filter".to_string(), string.to_owned());
+    /// Specifies a token filter. It is sent as `token_filters` with one element.
+    pub fn token_filter(self, token_filter: TokenFiltersType) -> TableCreateCommand {
+        self.token_filters(vec![token_filter])
+    }
+
+    /// Specifies multiple token filters which are applied in order.
+    pub fn token_filters(mut self, token_filters: Vec<TokenFiltersType>) -> TableCreateCommand {
+        let filters = token_filters.into_iter().map(|filter| format!("{}", filter)).collect();
+        let string = util::split_values_vec(filters);
+        self.arguments.insert("token_filters".to_string(), string.to_owned());
         self
     }
 
+    /// Tokenizers which must be available on the server.
+    ///
+    /// Pass it to `TokenizerListResponse::missing` to check them before sending.
+    pub fn required_tokenizers(&self) -> Vec<TokenizerType> {
+        self.arguments
+            .get("default_tokenizer")
+            .and_then(|tokenizer| TokenizerType::from_str(tokenizer).ok())
+            .into_iter()
+            .collect()
+    }
+
+    /// Normalizers which must be available on the server.
+    ///
+    /// Pass it to `NormalizerListResponse::missing` to check them before sending.
+    pub fn required_normalizers(&self) -> Vec<NormalizerType> {
+        self.arguments
+            .get("normalizer")
+            .and_then(|normalizer| NormalizerType::from_str(normalizer).ok())
+            .into_iter()
+            .collect()
+    }
+
+    /// Checks a combination of `flags`.
+    ///
+    /// See `table_flag_type::validate_flags` for the rules.
+    pub fn validate(&self) -> Result<(), TableFlagsError> {
+        let flags: Vec<TableFlagType> = match self.arguments.get("flags") {
+            Some(flags) => flags.split('|').filter_map(|flag| flag.parse().ok()).collect(),
+            None => vec![],
+        };
+        table_flag_type::validate_flags(&flags)
+    }
+
     pub fn build(self) -> (Command, Query) {
         let mut query: Query = vec![("name".to_string(), self.name)];
         for (key, value) in &self.arguments {
@@ -95,14 +150,45 @@ impl Commandable for TableCreateCommand {
 extendable!(TableCreateCommand);
 request_cancellable!(TableCreateCommand);
 request_timeoutable!(TableCreateCommand);
+version_checkable!(TableCreateCommand);
+
+impl PluginRequirable for TableCreateCommand {
+    fn required_plugins(&self) -> Vec<Plugin> {
+        let mut plugins = vec![];
+        if let Some(tokenizer) = self.arguments.get("default_tokenizer") {
+            if let Ok(TokenizerType::Mecab) = TokenizerType::from_str(tokenizer) {
+                plugins.push(Plugin::TokenizersMecab);
+            }
+        }
+        if let Some(normalizer) = self.arguments.get("normalizer") {
+            if normalizer.starts_with("NormalizerMySQL") {
+                plugins.push(Plugin::NormalizersMySQL);
+            }
+        }
+        if let Some(token_filters) = self.arguments.get("token_filters") {
+            for token_filter in token_filters.split(',') {
+                let plugin = match TokenFiltersType::from_str(token_filter.trim()) {
+                    Ok(TokenFiltersType::Stem) => Plugin::TokenFiltersStem,
+                    Ok(TokenFiltersType::StopWord) => Plugin::TokenFiltersStopWord,
+                    _ => continue,
+                };
+                if !plugins.contains(&plugin) {
+                    plugins.push(plugin);
+                }
+            }
+        }
+        plugins
+    }
+}
 
 #[cfg(test)]
 mod test {
     use super::*;
     use command::Command::TableCreate;
     use std::collections::HashMap;
-    use types::table_flag_type::TableFlagType;
+    use types::table_flag_type::{TableFlagType, TableFlagsError};
     use types::data_type::DataType;
+    use types::column_type::ColumnType;
     use types::tokenizer_type::TokenizerType;
     use types::normalizer_type::NormalizerType;
     use types::token_filters_type::TokenFiltersType;
@@ -110,6 +196,8 @@ mod test {
     use queryable::Queryable;
     use commandable::Commandable;
     use extendable::Extendable;
+    use plugin_requirable::PluginRequirable;
+    use types::plugin::Plugin;
 
     #[test]
     fn test_new() {
@@ -137,6 +225,19 @@ mod test {
         assert_eq!(expected, table_create);
     }
 
+    #[test]
+    fn test_required_plugins() {
+        let table_create = TableCreateCommand::new("Terms".to_string())
+            .default_tokenizer(TokenizerType::Mecab)
+            .normalizer(NormalizerType::Auto)
+            .token_filter(TokenFiltersType::Stem);
+        assert_eq!(vec![Plugin::TokenizersMecab, Plugin::TokenFiltersStem],
+                   table_create.required_plugins());
+        let bigram = TableCreateCommand::new("Terms".to_string())
+            .default_tokenizer(TokenizerType::Bigram);
+        assert!(bigram.required_plugins().is_empty());
+    }
+
     #[test]
     fn test_key_type() {
         let table_create = TableCreateCommand::new("Test".to_string())
@@ -151,6 +252,28 @@ mod test {
         assert_eq!(expected, table_create);
     }
 
+    #[test]
+    fn test_value_type() {
+        let table_create = TableCreateCommand::new("Test".to_string())
+            .value_type(DataType::UInt32);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("value_type".to_string(), "UInt32".to_string());
+        let expected = TableCreateCommand {
+            command: TableCreate,
+            name: "Test".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, table_create);
+    }
+
+    #[test]
+    fn test_value_type_with_reference() {
+        let table_create = TableCreateCommand::new("Test".to_string())
+            .value_type(ColumnType::Reference("Users".to_string()));
+        assert_eq!("table_create --name Test --value_type Users",
+                   table_create.to_command());
+    }
+
     #[test]
     fn test_default_tokenizer() {
         let table_create = TableCreateCommand::new("Test".to_string())
@@ -185,7 +308,7 @@ mod test {
         let table_create = TableCreateCommand::new("Test".to_string())
             .token_filter(TokenFiltersType::StopWord);
         let mut arg: HashMap<String, String> = HashMap::new();
-        arg.insert("token_filter".to_string(),
+        arg.insert("token_filters".to_string(),
                    "TokenFilterStopWord".to_string());
         let expected = TableCreateCommand {
             command: TableCreate,
@@ -193,6 +316,52 @@ mod test {
             arguments: arg,
         };
         assert_eq!(expected, table_create);
+        let command = TableCreateCommand::new("Test".to_string())
+            .token_filter(TokenFiltersType::StopWord)
+            .to_command();
+        assert_eq!("table_create --name Test --token_filters TokenFilterStopWord".to_string(),
+                   command);
+    }
+
+    #[test]
+    fn test_token_filters() {
+        let table_create = TableCreateCommand::new("Test".to_string())
+            .token_filters(vec![TokenFiltersType::StopWord, TokenFiltersType::Stem]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("token_filters".to_string(),
+                   "TokenFilterStopWord,TokenFilterStem".to_string());
+        let expected = TableCreateCommand {
+            command: TableCreate,
+            name: "Test".to_string(),
+            arguments: arg,
+        };
+        assert_eq!(expected, table_create);
+        assert_eq!(vec![Plugin::TokenFiltersStopWord, Plugin::TokenFiltersStem],
+                   table_create.required_plugins());
+    }
+
+    #[test]
+    fn test_required_tokenizers_and_normalizers() {
+        let table_create = TableCreateCommand::new("Lexicon".to_string())
+            .default_tokenizer(TokenizerType::Mecab)
+            .normalizer(NormalizerType::Auto);
+        assert_eq!(vec![TokenizerType::Mecab], table_create.required_tokenizers());
+        assert_eq!(vec![NormalizerType::Auto], table_create.required_normalizers());
+        let table_create = TableCreateCommand::new("Test".to_string());
+        assert!(table_create.required_tokenizers().is_empty());
+        assert!(table_create.required_normalizers().is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let table_create = TableCreateCommand::new("Terms".to_string())
+            .flags(vec![TableFlagType::PatKey, TableFlagType::KeyWithSIS]);
+        assert_eq!(Ok(()), table_create.validate());
+        assert_eq!(Ok(()), TableCreateCommand::new("Test".to_string()).validate());
+        let conflicted = TableCreateCommand::new("Test".to_string())
+            .flags(vec![TableFlagType::HashKey, TableFlagType::NoKey]);
+        assert_eq!(Err(TableFlagsError::Conflict(TableFlagType::HashKey, TableFlagType::NoKey)),
+                   conflicted.validate());
     }
 
     #[test]
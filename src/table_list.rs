@@ -29,6 +29,12 @@ impl TableListCommand {
         Default::default()
     }
 
+    /// Lists only tables whose name starts with `prefix`.
+    pub fn prefix(mut self, prefix: String) -> TableListCommand {
+        self.arguments.insert("prefix".to_string(), prefix);
+        self
+    }
+
     pub fn build(self) -> (Command, Query) {
         let mut query: Query = vec![];
         for (key, value) in &self.arguments {
@@ -57,6 +63,7 @@ impl Commandable for TableListCommand {
 extendable!(TableListCommand);
 request_cancellable!(TableListCommand);
 request_timeoutable!(TableListCommand);
+version_checkable!(TableListCommand);
 
 #[cfg(test)]
 mod test {
@@ -86,6 +93,25 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn test_prefix() {
+        let table_list = TableListCommand::new().prefix("Logs_".to_string());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("prefix".to_string(), "Logs_".to_string());
+        let expected = TableListCommand {
+            command: TableList,
+            arguments: arg,
+        };
+        assert_eq!(expected, table_list);
+    }
+
+    #[test]
+    fn test_commandable_with_prefix() {
+        let query = TableListCommand::new().prefix("Logs_".to_string()).to_command();
+        let cli_encoded = "table_list --prefix Logs_";
+        assert_eq!(cli_encoded.to_string(), query);
+    }
+
     #[test]
     fn test_queryable() {
         let query = TableListCommand::new().to_query();
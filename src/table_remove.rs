@@ -65,6 +65,7 @@ impl Commandable for TableRemoveCommand {
 extendable!(TableRemoveCommand);
 request_cancellable!(TableRemoveCommand);
 request_timeoutable!(TableRemoveCommand);
+version_checkable!(TableRemoveCommand);
 
 #[cfg(test)]
 mod test {
@@ -66,6 +66,7 @@ impl Commandable for TableRenameCommand {
 extendable!(TableRenameCommand);
 request_cancellable!(TableRenameCommand);
 request_timeoutable!(TableRenameCommand);
+version_checkable!(TableRenameCommand);
 
 #[cfg(test)]
 mod test {
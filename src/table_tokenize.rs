@@ -0,0 +1,207 @@
+use command::{Command, Query};
+use command::Command::TableTokenize;
+use std::collections::HashMap;
+use command_query::CommandQuery;
+use queryable::Queryable;
+use command_line::CommandLine;
+use commandable::Commandable;
+use extendable::Extendable;
+use request_cancellable::RequestCancellable;
+use request_timeoutable::RequestTimeoutable;
+use tokenize::{FlagType, ModeType};
+use util;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TableTokenizeCommand {
+    command: Command,
+    table: String,
+    string: String,
+    arguments: HashMap<String, String>,
+}
+
+impl Default for TableTokenizeCommand {
+    fn default() -> TableTokenizeCommand {
+        TableTokenizeCommand {
+            command: TableTokenize,
+            table: "".to_string(),
+            string: "".to_string(),
+            arguments: HashMap::new(),
+        }
+    }
+}
+
+impl TableTokenizeCommand {
+    /// `table` is a lexicon table which has tokenizer, normalizer and token filters.
+    pub fn new(table: String, string: String) -> TableTokenizeCommand {
+        TableTokenizeCommand {
+            table: table,
+            string: format!("\"{}\"", string),
+            ..TableTokenizeCommand::default()
+        }
+    }
+
+    pub fn flags(mut self, flags: Vec<FlagType>) -> TableTokenizeCommand {
+        let string = util::split_flags_vec(flags);
+        self.arguments.insert("flags".to_string(), string.to_owned());
+        self
+    }
+
+    pub fn mode(mut self, mode: ModeType) -> TableTokenizeCommand {
+        let string = format!("{}", mode);
+        self.arguments.insert("mode".to_string(), string.to_owned());
+        self
+    }
+
+    /// Outputs estimated size of each token in the index column.
+    pub fn index_column(mut self, index_column: String) -> TableTokenizeCommand {
+        self.arguments.insert("index_column".to_string(), index_column);
+        self
+    }
+
+    pub fn build(self) -> (Command, Query) {
+        let mut query: Query = vec![("table".to_string(), self.table),
+                                    ("string".to_string(), self.string)];
+        for (key, value) in &self.arguments {
+            query.push((key.to_owned(), value.to_owned()));
+        }
+        (TableTokenize, query)
+    }
+}
+
+impl Queryable for TableTokenizeCommand {
+    fn to_query(self) -> String {
+        let (command, query) = self.build();
+        let mut command = CommandQuery::new(command, query);
+        command.encode()
+    }
+}
+
+impl Commandable for TableTokenizeCommand {
+    fn to_command(self) -> String {
+        let (command, query) = self.build();
+        let mut command = CommandLine::new(command, query);
+        command.encode()
+    }
+}
+
+extendable!(TableTokenizeCommand);
+request_cancellable!(TableTokenizeCommand);
+request_timeoutable!(TableTokenizeCommand);
+version_checkable!(TableTokenizeCommand);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use command::Query;
+    use command::Command::TableTokenize;
+    use std::collections::HashMap;
+    use tokenize::{FlagType, ModeType};
+    use queryable::Queryable;
+    use commandable::Commandable;
+    use extendable::Extendable;
+
+    #[test]
+    fn test_new() {
+        let table_tokenize = TableTokenizeCommand::new("Terms".to_string(),
+                                                       "element".to_string());
+        let expected = TableTokenizeCommand {
+            command: TableTokenize,
+            table: "Terms".to_string(),
+            string: "\"element\"".to_string(),
+            arguments: HashMap::new(),
+        };
+        assert_eq!(expected, table_tokenize);
+    }
+
+    #[test]
+    fn test_flags() {
+        let table_tokenize = TableTokenizeCommand::new("Terms".to_string(),
+                                                       "element".to_string())
+            .flags(vec![(FlagType::None), (FlagType::EnableTokenizedDelimiter)]);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("flags".to_string(),
+                   "NONE|ENABLE_TOKENIZED_DELIMITER".to_string());
+        let expected = TableTokenizeCommand {
+            command: TableTokenize,
+            table: "Terms".to_string(),
+            string: "\"element\"".to_string(),
+            arguments: arg.to_owned(),
+        };
+        assert_eq!(expected, table_tokenize);
+    }
+
+    #[test]
+    fn test_mode() {
+        let table_tokenize = TableTokenizeCommand::new("Terms".to_string(),
+                                                       "element".to_string())
+            .mode(ModeType::Get);
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("mode".to_string(), format!("{}", ModeType::Get));
+        let expected = TableTokenizeCommand {
+            command: TableTokenize,
+            table: "Terms".to_string(),
+            string: "\"element\"".to_string(),
+            arguments: arg.to_owned(),
+        };
+        assert_eq!(expected, table_tokenize);
+    }
+
+    #[test]
+    fn test_index_column() {
+        let table_tokenize = TableTokenizeCommand::new("Terms".to_string(),
+                                                       "element".to_string())
+            .index_column("index".to_string());
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("index_column".to_string(), "index".to_string());
+        let expected = TableTokenizeCommand {
+            command: TableTokenize,
+            table: "Terms".to_string(),
+            string: "\"element\"".to_string(),
+            arguments: arg.to_owned(),
+        };
+        assert_eq!(expected, table_tokenize);
+    }
+
+    #[test]
+    fn test_build() {
+        let actual = TableTokenizeCommand::new("Terms".to_string(), "element".to_string())
+            .build();
+        let expected_query: Query = vec![("table".to_string(), "Terms".to_string()),
+                                         ("string".to_string(), "\"element\"".to_string())];
+        let expected = (TableTokenize, expected_query);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_queryable() {
+        let query = TableTokenizeCommand::new("Terms".to_string(), "element".to_string())
+            .to_query();
+        let url_encoded = "/d/table_tokenize?table=Terms&string=%22element%22";
+        assert_eq!(url_encoded.to_string(), query);
+    }
+
+    #[test]
+    fn test_commandable() {
+        let query = TableTokenizeCommand::new("Terms".to_string(), "element".to_string())
+            .to_command();
+        let cli_encoded = "table_tokenize --table Terms --string \"element\"";
+        assert_eq!(cli_encoded.to_string(), query);
+    }
+
+    #[test]
+    fn test_extendable() {
+        let mut arg: HashMap<String, String> = HashMap::new();
+        arg.insert("user".to_string(), "defined".to_string());
+        let expected = TableTokenizeCommand {
+            command: TableTokenize,
+            table: "Terms".to_string(),
+            string: "\"element\"".to_string(),
+            arguments: arg.to_owned(),
+        };
+        let query = TableTokenizeCommand::new("Terms".to_string(), "element".to_string());
+        unsafe {
+            let extended = query.set_arguments(arg.to_owned());
+            assert_eq!(expected, extended);
+        }
+    }
+}
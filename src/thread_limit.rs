@@ -63,6 +63,7 @@ impl Commandable for ThreadLimitCommand {
 extendable!(ThreadLimitCommand);
 request_cancellable!(ThreadLimitCommand);
 request_timeoutable!(ThreadLimitCommand);
+version_checkable!(ThreadLimitCommand);
 
 #[cfg(test)]
 mod test {
@@ -197,6 +197,7 @@ impl Commandable for TokenizeCommand {
 extendable!(TokenizeCommand);
 request_cancellable!(TokenizeCommand);
 request_timeoutable!(TokenizeCommand);
+version_checkable!(TokenizeCommand);
 
 #[cfg(test)]
 mod test {
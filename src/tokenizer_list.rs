@@ -57,6 +57,7 @@ impl Commandable for TokenizerListCommand {
 extendable!(TokenizerListCommand);
 request_cancellable!(TokenizerListCommand);
 request_timeoutable!(TokenizerListCommand);
+version_checkable!(TokenizerListCommand);
 
 #[cfg(test)]
 mod test {
@@ -59,6 +59,7 @@ impl Commandable for TruncateCommand {
 extendable!(TruncateCommand);
 request_cancellable!(TruncateCommand);
 request_timeoutable!(TruncateCommand);
+version_checkable!(TruncateCommand);
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,104 @@
+use std::fmt;
+use std::str::FromStr;
+use std::convert::AsRef;
+use self::CharType::{Null, Alpha, Digit, Symbol, Hiragana, Katakana, Kanji, Others, Emoji,
+                     ExtCharType};
+
+/// Character type which is returned from `normalize` and `tokenize` with `WITH_TYPES` flag.
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum CharType {
+    Null,
+    Alpha,
+    Digit,
+    Symbol,
+    Hiragana,
+    Katakana,
+    Kanji,
+    Others,
+    Emoji,
+    /// For future extensibility.
+    ExtCharType(String),
+}
+
+impl AsRef<str> for CharType {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Null => "null",
+            Alpha => "alpha",
+            Digit => "digit",
+            Symbol => "symbol",
+            Hiragana => "hiragana",
+            Katakana => "katakana",
+            Kanji => "kanji",
+            Others => "others",
+            Emoji => "emoji",
+            ExtCharType(ref s) => s.as_ref(),
+        }
+    }
+}
+
+#[derive (Debug)]
+pub enum CharTypeError {
+    Empty,
+}
+
+impl FromStr for CharType {
+    type Err = CharTypeError;
+    fn from_str(s: &str) -> Result<CharType, CharTypeError> {
+        if s == "" {
+            Err(CharTypeError::Empty)
+        } else {
+            Ok(match s {
+                "null" | "Null" => Null,
+                "alpha" | "Alpha" => Alpha,
+                "digit" | "Digit" => Digit,
+                "symbol" | "Symbol" => Symbol,
+                "hiragana" | "Hiragana" => Hiragana,
+                "katakana" | "Katakana" => Katakana,
+                "kanji" | "Kanji" => Kanji,
+                "others" | "Others" => Others,
+                "emoji" | "Emoji" => Emoji,
+                _ => ExtCharType(s.to_owned()),
+            })
+        }
+    }
+}
+
+impl fmt::Display for CharType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(CharType::Alpha, FromStr::from_str("alpha").unwrap());
+        assert_eq!(CharType::Hiragana, FromStr::from_str("Hiragana").unwrap());
+        assert_eq!(CharType::ExtCharType("added-type".to_owned()),
+                   FromStr::from_str("added-type").unwrap());
+        let x: Result<CharType, _> = FromStr::from_str("");
+        if let Err(CharTypeError::Empty) = x {
+        } else {
+            panic!("An empty char type is invalid!")
+        }
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("kanji".to_owned(), format!("{}", CharType::Kanji));
+        assert_eq!("added-type".to_owned(),
+                   format!("{}", CharType::ExtCharType("added-type".to_owned())));
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(CharType::Digit.as_ref(), "digit");
+        assert_eq!(CharType::ExtCharType("added-type".to_owned()).as_ref(),
+                   "added-type");
+    }
+}
@@ -2,12 +2,11 @@ use std::fmt;
 use std::str::FromStr;
 use std::convert::AsRef;
 use self::ColumnFlagType::{Scalar, Vector, Index, WithSection, WithWeight, WithPosition,
+                           IndexSmall, IndexMedium, WeightFloat32, MissingAdd, MissingIgnore,
+                           MissingNil, InvalidError, InvalidWarn, InvalidIgnore,
                            ExtColumnFlagType};
 #[cfg(feature="unstable")]
-use self::ColumnFlagType::{CompressZlib, CompressLz4};
-#[cfg(all(feature="unstable", feature="groonga_611"))]
-use self::ColumnFlagType::CompressZstd;
-
+use self::ColumnFlagType::{CompressZlib, CompressLz4, CompressZstd};
 
 #[derive (Clone, PartialEq, Eq, Debug)]
 pub enum ColumnFlagType {
@@ -17,11 +16,30 @@ pub enum ColumnFlagType {
     WithSection,
     WithWeight,
     WithPosition,
+    /// Index column which uses less memory for a small number of records.
+    IndexSmall,
+    /// Index column which uses less memory for a medium number of records.
+    IndexMedium,
+    /// Stores weights as 32bit floating point numbers. It needs `WithWeight`.
+    WeightFloat32,
+    /// Adds a missing referenced record. It is the default behavior.
+    MissingAdd,
+    /// Ignores a missing referenced record.
+    MissingIgnore,
+    /// Stores `NULL` for a missing referenced record.
+    MissingNil,
+    /// Reports an error for an invalid value. It is the default behavior.
+    InvalidError,
+    /// Reports a warning and stores the default value for an invalid value.
+    InvalidWarn,
+    /// Stores the default value for an invalid value silently.
+    InvalidIgnore,
     #[cfg(feature="unstable")]
     CompressZlib,
     #[cfg(feature="unstable")]
     CompressLz4,
-    #[cfg(all(feature="unstable", feature="groonga_611"))]
+    /// It needs Groonga 6.1.1 or later. See `version_checkable`.
+    #[cfg(feature="unstable")]
     CompressZstd,
     /// For future extensibility.
     ExtColumnFlagType(String),
@@ -36,11 +54,20 @@ impl AsRef<str> for ColumnFlagType {
             WithSection => "WITH_SECTION",
             WithWeight => "WITH_WEIGHT",
             WithPosition => "WITH_POSITION",
+            IndexSmall => "INDEX_SMALL",
+            IndexMedium => "INDEX_MEDIUM",
+            WeightFloat32 => "WEIGHT_FLOAT32",
+            MissingAdd => "MISSING_ADD",
+            MissingIgnore => "MISSING_IGNORE",
+            MissingNil => "MISSING_NIL",
+            InvalidError => "INVALID_ERROR",
+            InvalidWarn => "INVALID_WARN",
+            InvalidIgnore => "INVALID_IGNORE",
             #[cfg(feature="unstable")]
             CompressZlib => "COMPRESS_ZLIB",
             #[cfg(feature="unstable")]
             CompressLz4 => "COMPRESS_LZ4",
-            #[cfg(all(feature="unstable", feature="groonga_611"))]
+            #[cfg(feature="unstable")]
             CompressZstd => "COMPRESS_ZSTD",
             ExtColumnFlagType(ref s) => s.as_ref(),
         }
@@ -65,11 +92,20 @@ impl FromStr for ColumnFlagType {
                 "WithSection" | "WITH_SECTION" => WithSection,
                 "WithWeight" | "WITH_WEIGHT" => WithWeight,
                 "WithPosition" | "WITH_POSITION" => WithPosition,
+                "IndexSmall" | "INDEX_SMALL" => IndexSmall,
+                "IndexMedium" | "INDEX_MEDIUM" => IndexMedium,
+                "WeightFloat32" | "WEIGHT_FLOAT32" => WeightFloat32,
+                "MissingAdd" | "MISSING_ADD" => MissingAdd,
+                "MissingIgnore" | "MISSING_IGNORE" => MissingIgnore,
+                "MissingNil" | "MISSING_NIL" => MissingNil,
+                "InvalidError" | "INVALID_ERROR" => InvalidError,
+                "InvalidWarn" | "INVALID_WARN" => InvalidWarn,
+                "InvalidIgnore" | "INVALID_IGNORE" => InvalidIgnore,
                 #[cfg(feature="unstable")]
                 "Zlib" | "CompressZlib" | "COMPRESS_ZLIB" => CompressZlib,
                 #[cfg(feature="unstable")]
                 "Lz4" | "CompressLz4" | "COMPRESS_LZ4" => CompressLz4,
-                #[cfg(all(feature="unstable", feature="groonga_611"))]
+                #[cfg(feature="unstable")]
                 "Zstd" | "CompressZstd" | "COMPRESS_ZSTD" => CompressZstd,
                 _ => ExtColumnFlagType(s.to_owned()),
             })
@@ -86,17 +122,106 @@ impl fmt::Display for ColumnFlagType {
             WithSection => "WITH_SECTION",
             WithWeight => "WITH_WEIGHT",
             WithPosition => "WITH_POSITION",
+            IndexSmall => "INDEX_SMALL",
+            IndexMedium => "INDEX_MEDIUM",
+            WeightFloat32 => "WEIGHT_FLOAT32",
+            MissingAdd => "MISSING_ADD",
+            MissingIgnore => "MISSING_IGNORE",
+            MissingNil => "MISSING_NIL",
+            InvalidError => "INVALID_ERROR",
+            InvalidWarn => "INVALID_WARN",
+            InvalidIgnore => "INVALID_IGNORE",
             #[cfg(feature="unstable")]
             CompressZlib => "COMPRESS_ZLIB",
             #[cfg(feature="unstable")]
             CompressLz4 => "COMPRESS_LZ4",
-            #[cfg(all(feature="unstable", feature="groonga_611"))]
+            #[cfg(feature="unstable")]
             CompressZstd => "COMPRESS_ZSTD",
             ExtColumnFlagType(ref s) => s.as_ref(),
         })
     }
 }
 
+impl ColumnFlagType {
+    // Compression flags are parsed as `ExtColumnFlagType` without `unstable` feature.
+    fn is_compression(&self) -> bool {
+        match *self {
+            #[cfg(feature="unstable")]
+            CompressZlib | CompressLz4 => true,
+            #[cfg(feature="unstable")]
+            CompressZstd => true,
+            ExtColumnFlagType(ref s) => s.starts_with("COMPRESS_"),
+            _ => false,
+        }
+    }
+
+    // Flags in the same group cannot be set together.
+    fn group(&self) -> Option<&'static str> {
+        match *self {
+            Scalar | Vector | Index => Some("column"),
+            IndexSmall | IndexMedium => Some("index_size"),
+            MissingAdd | MissingIgnore | MissingNil => Some("missing"),
+            InvalidError | InvalidWarn | InvalidIgnore => Some("invalid"),
+            _ if self.is_compression() => Some("compress"),
+            _ => None,
+        }
+    }
+
+    fn is_index_only(&self) -> bool {
+        [WithSection, WithPosition, IndexSmall, IndexMedium].contains(self)
+    }
+
+    fn is_data_only(&self) -> bool {
+        match *self {
+            MissingAdd | MissingIgnore | MissingNil | InvalidError | InvalidWarn |
+            InvalidIgnore => true,
+            _ => self.is_compression(),
+        }
+    }
+}
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum ColumnFlagsError {
+    /// The flags cannot be set together.
+    Conflict(ColumnFlagType, ColumnFlagType),
+    /// The first flag needs the second flag.
+    Requires(ColumnFlagType, ColumnFlagType),
+}
+
+/// Checks a combination of column flags.
+///
+/// Only one of `Scalar`, `Vector` and `Index` can be set and `Scalar` is assumed without them.
+/// Index flags need `Index`, and compression, `Missing*` and `Invalid*` flags
+/// are only for scalar and vector columns.
+pub fn validate_flags(flags: &[ColumnFlagType]) -> Result<(), ColumnFlagsError> {
+    for (i, flag) in flags.iter().enumerate() {
+        for other in &flags[i + 1..] {
+            if flag != other && flag.group().is_some() && flag.group() == other.group() {
+                return Err(ColumnFlagsError::Conflict(flag.clone(), other.clone()));
+            }
+        }
+    }
+    let column = flags.iter()
+        .find(|flag| flag.group() == Some("column"))
+        .cloned()
+        .unwrap_or(Scalar);
+    for flag in flags {
+        if flag.is_index_only() && column != Index {
+            return Err(ColumnFlagsError::Requires(flag.clone(), Index));
+        }
+        if flag.is_data_only() && column == Index {
+            return Err(ColumnFlagsError::Conflict(flag.clone(), Index));
+        }
+        if *flag == WithWeight && column == Scalar {
+            return Err(ColumnFlagsError::Conflict(WithWeight, Scalar));
+        }
+        if *flag == WeightFloat32 && !flags.contains(&WithWeight) {
+            return Err(ColumnFlagsError::Requires(WeightFloat32, WithWeight));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -107,6 +232,10 @@ mod test {
         assert_eq!(ColumnFlagType::Scalar, FromStr::from_str("Scalar").unwrap());
         assert_eq!(ColumnFlagType::Scalar,
                    FromStr::from_str("ColumnScalar").unwrap());
+        assert_eq!(ColumnFlagType::IndexSmall,
+                   FromStr::from_str("INDEX_SMALL").unwrap());
+        assert_eq!(ColumnFlagType::MissingNil,
+                   FromStr::from_str("MissingNil").unwrap());
         assert_eq!(ColumnFlagType::ExtColumnFlagType("AddedColumnFlag".to_owned()),
                    FromStr::from_str("AddedColumnFlag").unwrap());
         let x: Result<ColumnFlagType, _> = FromStr::from_str("");
@@ -123,11 +252,6 @@ mod test {
                    FromStr::from_str("Zlib").unwrap());
         assert_eq!(ColumnFlagType::CompressLz4,
                    FromStr::from_str("CompressLz4").unwrap());
-    }
-
-    #[test]
-    #[cfg(all(feature="unstable", feature="groonga_611"))]
-    fn test_from_str_unstable_groonga_611() {
         assert_eq!(ColumnFlagType::CompressZstd,
                    FromStr::from_str("Zstd").unwrap());
     }
@@ -146,11 +270,6 @@ mod test {
     fn test_fmt_unstable() {
         assert_eq!("COMPRESS_ZLIB".to_owned(),
                    format!("{}", ColumnFlagType::CompressZlib));
-    }
-
-    #[test]
-    #[cfg(all(feature="unstable", feature="groonga_611"))]
-    fn test_from_fmt_unstable_groonga_611() {
         assert_eq!("COMPRESS_ZSTD".to_owned(),
                    format!("{}", ColumnFlagType::CompressZstd));
     }
@@ -158,6 +277,8 @@ mod test {
     #[test]
     fn test_as_str() {
         assert_eq!(ColumnFlagType::Index.as_ref(), "COLUMN_INDEX");
+        assert_eq!(ColumnFlagType::WeightFloat32.as_ref(), "WEIGHT_FLOAT32");
+        assert_eq!(ColumnFlagType::InvalidWarn.as_ref(), "INVALID_WARN");
         assert_eq!(ColumnFlagType::ExtColumnFlagType("AddedColumnFlag".to_owned()).as_ref(),
                    "AddedColumnFlag");
     }
@@ -166,11 +287,49 @@ mod test {
     #[cfg(feature="unstable")]
     fn test_as_str_unstable() {
         assert_eq!(ColumnFlagType::CompressLz4.as_ref(), "COMPRESS_LZ4");
+        assert_eq!(ColumnFlagType::CompressZstd.as_ref(), "COMPRESS_ZSTD");
     }
 
     #[test]
-    #[cfg(all(feature="unstable", feature="groonga_611"))]
-    fn test_as_str_unstable_groonga_611() {
-        assert_eq!(ColumnFlagType::CompressZstd.as_ref(), "COMPRESS_ZSTD");
+    fn test_validate_flags() {
+        use super::ColumnFlagType::*;
+        assert_eq!(Ok(()), validate_flags(&[Index, WithSection, WithPosition, IndexSmall]));
+        assert_eq!(Ok(()), validate_flags(&[Vector, WithWeight, WeightFloat32]));
+        assert_eq!(Ok(()), validate_flags(&[MissingIgnore, InvalidWarn]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(Scalar, Index)),
+                   validate_flags(&[Scalar, Index]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(IndexSmall, IndexMedium)),
+                   validate_flags(&[Index, IndexSmall, IndexMedium]));
+        assert_eq!(Err(ColumnFlagsError::Requires(WithPosition, Index)),
+                   validate_flags(&[Vector, WithPosition]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(MissingNil, Index)),
+                   validate_flags(&[Index, MissingNil]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(WithWeight, Scalar)),
+                   validate_flags(&[WithWeight]));
+        assert_eq!(Err(ColumnFlagsError::Requires(WeightFloat32, WithWeight)),
+                   validate_flags(&[Vector, WeightFloat32]));
+    }
+
+    #[test]
+    fn test_validate_flags_with_compression() {
+        use super::ColumnFlagType::*;
+        let zlib = ColumnFlagType::from_str("COMPRESS_ZLIB").unwrap();
+        let lz4 = ColumnFlagType::from_str("COMPRESS_LZ4").unwrap();
+        assert_eq!(Ok(()), validate_flags(&[Scalar, zlib.clone()]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(zlib.clone(), Index)),
+                   validate_flags(&[Index, zlib.clone()]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(zlib.clone(), lz4.clone())),
+                   validate_flags(&[Vector, zlib, lz4]));
+    }
+
+    #[test]
+    #[cfg(feature="unstable")]
+    fn test_validate_flags_unstable() {
+        use super::ColumnFlagType::*;
+        assert_eq!(Ok(()), validate_flags(&[Scalar, CompressZlib]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(CompressLz4, Index)),
+                   validate_flags(&[Index, CompressLz4]));
+        assert_eq!(Err(ColumnFlagsError::Conflict(CompressZlib, CompressLz4)),
+                   validate_flags(&[Vector, CompressZlib, CompressLz4]));
     }
 }
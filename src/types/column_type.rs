@@ -0,0 +1,126 @@
+use std::fmt;
+use types::data_type::DataType;
+use types::column_flag_type::ColumnFlagType;
+
+/// Value type of a column.
+///
+/// `DataType` can be converted into `ColumnType::Builtin` with `From`.
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum ColumnType {
+    /// A built-in type such as `ShortText`.
+    Builtin(DataType),
+    /// A vector of a built-in type such as `ShortText`.
+    Vector(DataType),
+    /// A reference to a record of the table.
+    Reference(String),
+    /// A vector of references to records of the table.
+    /// Each element has its weight when `with_weight` is true.
+    ReferenceVector { table: String, with_weight: bool },
+}
+
+impl ColumnType {
+    pub fn is_reference(&self) -> bool {
+        match *self {
+            ColumnType::Builtin(DataType::ExtDataType(_)) |
+            ColumnType::Vector(DataType::ExtDataType(_)) |
+            ColumnType::Reference(_) |
+            ColumnType::ReferenceVector { .. } => true,
+            ColumnType::Builtin(_) |
+            ColumnType::Vector(_) => false,
+        }
+    }
+
+    pub fn is_vector(&self) -> bool {
+        self.flags().contains(&ColumnFlagType::Vector)
+    }
+
+    /// Flags which this type requires.
+    pub fn flags(&self) -> Vec<ColumnFlagType> {
+        match *self {
+            ColumnType::ReferenceVector { with_weight: true, .. } => {
+                vec![ColumnFlagType::Vector, ColumnFlagType::WithWeight]
+            }
+            ColumnType::Vector(_) |
+            ColumnType::ReferenceVector { with_weight: false, .. } => vec![ColumnFlagType::Vector],
+            _ => vec![ColumnFlagType::Scalar],
+        }
+    }
+}
+
+impl From<DataType> for ColumnType {
+    fn from(data_type: DataType) -> ColumnType {
+        ColumnType::Builtin(data_type)
+    }
+}
+
+impl fmt::Display for ColumnType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ColumnType::Builtin(ref data_type) |
+            ColumnType::Vector(ref data_type) => write!(fmt, "{}", data_type),
+            ColumnType::Reference(ref table) |
+            ColumnType::ReferenceVector { ref table, .. } => fmt.write_str(table),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::data_type::DataType;
+    use types::column_flag_type::ColumnFlagType;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("ShortText".to_owned(),
+                   format!("{}", ColumnType::from(DataType::ShortText)));
+        assert_eq!("Users".to_owned(),
+                   format!("{}", ColumnType::Reference("Users".to_owned())));
+        let tags = ColumnType::ReferenceVector {
+            table: "Tags".to_owned(),
+            with_weight: true,
+        };
+        assert_eq!("Tags".to_owned(), format!("{}", tags));
+        assert_eq!("ShortText".to_owned(),
+                   format!("{}", ColumnType::Vector(DataType::ShortText)));
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(vec![ColumnFlagType::Scalar],
+                   ColumnType::Reference("Users".to_owned()).flags());
+        let tags = ColumnType::ReferenceVector {
+            table: "Tags".to_owned(),
+            with_weight: false,
+        };
+        assert_eq!(vec![ColumnFlagType::Vector], tags.flags());
+        assert_eq!(vec![ColumnFlagType::Vector],
+                   ColumnType::Vector(DataType::ShortText).flags());
+        let weighted = ColumnType::ReferenceVector {
+            table: "Tags".to_owned(),
+            with_weight: true,
+        };
+        assert_eq!(vec![ColumnFlagType::Vector, ColumnFlagType::WithWeight],
+                   weighted.flags());
+    }
+
+    #[test]
+    fn test_is_reference() {
+        assert!(!ColumnType::from(DataType::UInt32).is_reference());
+        assert!(ColumnType::from(DataType::ExtDataType("Users".to_owned())).is_reference());
+        assert!(ColumnType::Reference("Users".to_owned()).is_reference());
+        assert!(!ColumnType::Vector(DataType::ShortText).is_reference());
+    }
+
+    #[test]
+    fn test_is_vector() {
+        assert!(ColumnType::Vector(DataType::ShortText).is_vector());
+        assert!(ColumnType::ReferenceVector {
+                table: "Tags".to_owned(),
+                with_weight: false,
+            }
+            .is_vector());
+        assert!(!ColumnType::from(DataType::ShortText).is_vector());
+        assert!(!ColumnType::Reference("Users".to_owned()).is_vector());
+    }
+}
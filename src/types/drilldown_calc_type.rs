@@ -1,7 +1,7 @@
 use std::fmt;
 use std::str::FromStr;
 use std::convert::AsRef;
-use self::CalcType::{None, Count, Max, Min, Sum, Avg, ExtCalcType};
+use self::CalcType::{None, Count, Max, Min, Sum, Avg, Mean, ExtCalcType};
 
 #[derive (Debug)]
 pub enum CalcTypeError {
@@ -16,6 +16,8 @@ pub enum CalcType {
     Min,
     Sum,
     Avg,
+    /// Alias of `Avg` in newer Groonga.
+    Mean,
     /// For future extensibility.
     ExtCalcType(String),
 }
@@ -29,6 +31,7 @@ impl AsRef<str> for CalcType {
             Min => "MIN",
             Sum => "SUM",
             Avg => "AVG",
+            Mean => "MEAN",
             ExtCalcType(ref s) => s.as_ref(),
         }
     }
@@ -43,6 +46,7 @@ impl fmt::Display for CalcType {
             Min => "MIN",
             Sum => "SUM",
             Avg => "AVG",
+            Mean => "MEAN",
             ExtCalcType(ref s) => s.as_ref(),
         })
     }
@@ -61,6 +65,7 @@ impl FromStr for CalcType {
                 "Min" | "MIN" => Min,
                 "Sum" | "SUM" => Sum,
                 "Avg" | "AVG" => Avg,
+                "Mean" | "MEAN" => Mean,
                 _ => ExtCalcType(s.to_owned()),
             })
         }
@@ -78,6 +83,7 @@ mod test {
         assert_eq!(CalcType::None, FromStr::from_str("None").unwrap());
         assert_eq!(CalcType::Count, FromStr::from_str("Count").unwrap());
         assert_eq!(CalcType::Max, FromStr::from_str("Max").unwrap());
+        assert_eq!(CalcType::Mean, FromStr::from_str("MEAN").unwrap());
         assert_eq!(CalcType::ExtCalcType("AddedType".to_owned()),
                    FromStr::from_str("AddedType").unwrap());
         let x: Result<CalcType, _> = FromStr::from_str("");
@@ -95,6 +101,7 @@ mod test {
         assert_eq!("MIN".to_owned(), format!("{}", CalcType::Min));
         assert_eq!("SUM".to_owned(), format!("{}", CalcType::Sum));
         assert_eq!("AVG".to_owned(), format!("{}", CalcType::Avg));
+        assert_eq!("MEAN".to_owned(), format!("{}", CalcType::Mean));
         assert_eq!("AddedType".to_owned(),
                    format!("{}", CalcType::ExtCalcType("AddedType".to_owned())));
     }
@@ -0,0 +1,94 @@
+use std::fmt;
+use std::str::FromStr;
+use std::convert::AsRef;
+use self::GeoApproximateType::{Rectangle, Sphere, Ellipsoid, ExtGeoApproximateType};
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum GeoApproximateType {
+    Rectangle,
+    Sphere,
+    Ellipsoid,
+    /// For future extensibility.
+    ExtGeoApproximateType(String),
+}
+
+impl AsRef<str> for GeoApproximateType {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Rectangle => "rectangle",
+            Sphere => "sphere",
+            Ellipsoid => "ellipsoid",
+            ExtGeoApproximateType(ref s) => s.as_ref(),
+        }
+    }
+}
+
+#[derive (Debug)]
+pub enum GeoApproximateTypeError {
+    Empty,
+}
+
+impl FromStr for GeoApproximateType {
+    type Err = GeoApproximateTypeError;
+    fn from_str(s: &str) -> Result<GeoApproximateType, GeoApproximateTypeError> {
+        if s == "" {
+            Err(GeoApproximateTypeError::Empty)
+        } else {
+            Ok(match s {
+                "rectangle" | "rect" | "Rectangle" | "RECTANGLE" => Rectangle,
+                "sphere" | "sphr" | "Sphere" | "SPHERE" => Sphere,
+                "ellipsoid" | "ellip" | "Ellipsoid" | "ELLIPSOID" => Ellipsoid,
+                _ => ExtGeoApproximateType(s.to_owned()),
+            })
+        }
+    }
+}
+
+impl fmt::Display for GeoApproximateType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            Rectangle => "rectangle",
+            Sphere => "sphere",
+            Ellipsoid => "ellipsoid",
+            ExtGeoApproximateType(ref s) => s.as_ref(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(GeoApproximateType::Rectangle,
+                   FromStr::from_str("rectangle").unwrap());
+        assert_eq!(GeoApproximateType::Sphere, FromStr::from_str("sphr").unwrap());
+        assert_eq!(GeoApproximateType::Ellipsoid,
+                   FromStr::from_str("ELLIPSOID").unwrap());
+        assert_eq!(GeoApproximateType::ExtGeoApproximateType("ADDED_TYPE".to_owned()),
+                   FromStr::from_str("ADDED_TYPE").unwrap());
+        let x: Result<GeoApproximateType, _> = FromStr::from_str("");
+        if let Err(GeoApproximateTypeError::Empty) = x {
+        } else {
+            panic!("An empty approximate type is invalid!")
+        }
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("sphere".to_owned(),
+                   format!("{}", GeoApproximateType::Sphere));
+        assert_eq!("ADDED_TYPE".to_owned(),
+                   format!("{}",
+                           GeoApproximateType::ExtGeoApproximateType("ADDED_TYPE".to_owned())));
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(GeoApproximateType::Rectangle.as_ref(), "rectangle");
+        assert_eq!(GeoApproximateType::ExtGeoApproximateType("ADDED_TYPE".to_owned()).as_ref(),
+                   "ADDED_TYPE");
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+use std::str::FromStr;
+
+const MILLISECONDS_PER_DEGREE: f64 = 3600000.0;
+
+/// A point which is stored into `TokyoGeoPoint` or `WGS84GeoPoint` column.
+///
+/// Latitude and longitude are held in milliseconds like Groonga does.
+#[derive (Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GeoPoint {
+    latitude: i64,
+    longitude: i64,
+}
+
+impl GeoPoint {
+    pub fn from_milliseconds(latitude: i64, longitude: i64) -> GeoPoint {
+        GeoPoint {
+            latitude: latitude,
+            longitude: longitude,
+        }
+    }
+
+    pub fn from_degrees(latitude: f64, longitude: f64) -> GeoPoint {
+        GeoPoint {
+            latitude: (latitude * MILLISECONDS_PER_DEGREE).round() as i64,
+            longitude: (longitude * MILLISECONDS_PER_DEGREE).round() as i64,
+        }
+    }
+
+    pub fn latitude(&self) -> i64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> i64 {
+        self.longitude
+    }
+
+    /// Formats as `${LATITUDE}x${LONGITUDE}` in milliseconds.
+    pub fn to_milliseconds(&self) -> String {
+        format!("{}x{}", self.latitude, self.longitude)
+    }
+
+    /// Formats as `${LATITUDE}x${LONGITUDE}` in degrees.
+    /// Degrees are rounded to 6 decimal places.
+    pub fn to_degrees(&self) -> String {
+        format!("{:.6}x{:.6}",
+                self.latitude as f64 / MILLISECONDS_PER_DEGREE,
+                self.longitude as f64 / MILLISECONDS_PER_DEGREE)
+    }
+}
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum GeoPointError {
+    Empty,
+    InvalidFormat(String),
+}
+
+/// Accepts both milliseconds and degrees notation.
+/// `x` and `,` are accepted as a separator.
+impl FromStr for GeoPoint {
+    type Err = GeoPointError;
+    fn from_str(s: &str) -> Result<GeoPoint, GeoPointError> {
+        if s == "" {
+            return Err(GeoPointError::Empty);
+        }
+        let parts: Vec<&str> = s.split(&['x', ','][..]).map(|p| p.trim()).collect();
+        if parts.len() != 2 {
+            return Err(GeoPointError::InvalidFormat(s.to_owned()));
+        }
+        if parts.iter().any(|p| p.contains('.')) {
+            match (parts[0].parse::<f64>(), parts[1].parse::<f64>()) {
+                (Ok(latitude), Ok(longitude)) => Ok(GeoPoint::from_degrees(latitude, longitude)),
+                _ => Err(GeoPointError::InvalidFormat(s.to_owned())),
+            }
+        } else {
+            match (parts[0].parse::<i64>(), parts[1].parse::<i64>()) {
+                (Ok(latitude), Ok(longitude)) => {
+                    Ok(GeoPoint::from_milliseconds(latitude, longitude))
+                }
+                _ => Err(GeoPointError::InvalidFormat(s.to_owned())),
+            }
+        }
+    }
+}
+
+impl fmt::Display for GeoPoint {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.to_milliseconds())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_degrees() {
+        let point = GeoPoint::from_degrees(35.681382, 139.766084);
+        assert_eq!(128452975, point.latitude());
+        assert_eq!(503157902, point.longitude());
+    }
+
+    #[test]
+    fn test_to_milliseconds() {
+        let point = GeoPoint::from_milliseconds(128452975, 503157902);
+        assert_eq!("128452975x503157902".to_owned(), point.to_milliseconds());
+    }
+
+    #[test]
+    fn test_to_degrees() {
+        let point = GeoPoint::from_degrees(35.681382, -139.766084);
+        assert_eq!("35.681382x-139.766084".to_owned(), point.to_degrees());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(GeoPoint::from_milliseconds(128452975, 503157902),
+                   FromStr::from_str("128452975x503157902").unwrap());
+        assert_eq!(GeoPoint::from_degrees(35.681382, 139.766084),
+                   FromStr::from_str("35.681382,139.766084").unwrap());
+        let x: Result<GeoPoint, _> = FromStr::from_str("");
+        assert_eq!(Err(GeoPointError::Empty), x);
+        let y: Result<GeoPoint, _> = FromStr::from_str("35.6x");
+        assert_eq!(Err(GeoPointError::InvalidFormat("35.6x".to_owned())), y);
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("128452975x503157902".to_owned(),
+                   format!("{}", GeoPoint::from_milliseconds(128452975, 503157902)));
+    }
+}
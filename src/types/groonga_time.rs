@@ -0,0 +1,171 @@
+use std::fmt;
+
+const MICROSECONDS_PER_SECOND: i64 = 1000000;
+const SECONDS_PER_DAY: i64 = 86400;
+
+fn floor_div(value: i64, divisor: i64) -> i64 {
+    if value >= 0 {
+        value / divisor
+    } else {
+        (value - divisor + 1) / divisor
+    }
+}
+
+// Converts days since 1970-01-01 into (year, month, day).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = floor_div(z, 146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month as u32, day as u32)
+}
+
+// Converts (year, month, day) into days since 1970-01-01.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = floor_div(year, 400);
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// A value of `Time` column.
+///
+/// It is held in microseconds since the UNIX epoch and formatted in UTC
+/// like `2016/10/18 12:34:56` or `2016/10/18 12:34:56.789000`.
+///
+/// Groonga interprets the formatted value in its local time zone.
+/// Use `unix_time()` for command arguments to avoid the difference.
+#[derive (Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GroongaTime {
+    microseconds: i64,
+}
+
+impl GroongaTime {
+    pub fn from_seconds(seconds: i64) -> GroongaTime {
+        GroongaTime { microseconds: seconds * MICROSECONDS_PER_SECOND }
+    }
+
+    pub fn from_microseconds(microseconds: i64) -> GroongaTime {
+        GroongaTime { microseconds: microseconds }
+    }
+
+    /// Midnight of the date in UTC.
+    pub fn from_date(year: i64, month: u32, day: u32) -> GroongaTime {
+        GroongaTime::from_seconds(days_from_civil(year, month, day) * SECONDS_PER_DAY)
+    }
+
+    pub fn from_datetime(year: i64,
+                         month: u32,
+                         day: u32,
+                         hour: u32,
+                         minute: u32,
+                         second: u32)
+                         -> GroongaTime {
+        let seconds = hour as i64 * 3600 + minute as i64 * 60 + second as i64;
+        GroongaTime::from_seconds(days_from_civil(year, month, day) * SECONDS_PER_DAY + seconds)
+    }
+
+    /// Seconds since the UNIX epoch. Fractions are rounded down.
+    pub fn seconds(&self) -> i64 {
+        floor_div(self.microseconds, MICROSECONDS_PER_SECOND)
+    }
+
+    pub fn microseconds(&self) -> i64 {
+        self.microseconds
+    }
+
+    /// Days since 1970-01-01.
+    pub fn days(&self) -> i64 {
+        floor_div(self.seconds(), SECONDS_PER_DAY)
+    }
+
+    /// Returns (year, month, day) in UTC.
+    pub fn date(&self) -> (i64, u32, u32) {
+        civil_from_days(self.days())
+    }
+
+    /// Seconds since the UNIX epoch like `1476748800` or `1476748800.500000`.
+    ///
+    /// Groonga casts it into `Time` regardless of the server's time zone.
+    pub fn unix_time(&self) -> String {
+        let sign = if self.microseconds < 0 { "-" } else { "" };
+        let microseconds = self.microseconds.abs();
+        let seconds = microseconds / MICROSECONDS_PER_SECOND;
+        let fraction = microseconds % MICROSECONDS_PER_SECOND;
+        if fraction == 0 {
+            format!("{}{}", sign, seconds)
+        } else {
+            format!("{}{}.{:06}", sign, seconds, fraction)
+        }
+    }
+}
+
+impl fmt::Display for GroongaTime {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.date();
+        let seconds = self.seconds() - self.days() * SECONDS_PER_DAY;
+        try!(write!(fmt,
+                    "{:04}/{:02}/{:02} {:02}:{:02}:{:02}",
+                    year,
+                    month,
+                    day,
+                    seconds / 3600,
+                    seconds % 3600 / 60,
+                    seconds % 60));
+        let fraction = self.microseconds - self.seconds() * MICROSECONDS_PER_SECOND;
+        if fraction != 0 {
+            try!(write!(fmt, ".{:06}", fraction));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_civil() {
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!((1969, 12, 31), civil_from_days(-1));
+        assert_eq!((2016, 2, 29), civil_from_days(days_from_civil(2016, 2, 29)));
+        assert_eq!(17092, days_from_civil(2016, 10, 18));
+    }
+
+    #[test]
+    fn test_from_datetime() {
+        let time = GroongaTime::from_datetime(2016, 10, 18, 12, 34, 56);
+        assert_eq!(GroongaTime::from_seconds(1476794096), time);
+        assert_eq!(GroongaTime::from_seconds(1476748800),
+                   GroongaTime::from_date(2016, 10, 18));
+        assert_eq!((2016, 10, 18), time.date());
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("2016/10/18 12:34:56".to_owned(),
+                   format!("{}", GroongaTime::from_seconds(1476794096)));
+        assert_eq!("2016/10/18 12:34:56.789000".to_owned(),
+                   format!("{}", GroongaTime::from_microseconds(1476794096789000)));
+        assert_eq!("1969/12/31 23:59:59.500000".to_owned(),
+                   format!("{}", GroongaTime::from_microseconds(-500000)));
+    }
+
+    #[test]
+    fn test_unix_time() {
+        assert_eq!("1476748800".to_owned(),
+                   GroongaTime::from_date(2016, 10, 18).unix_time());
+        assert_eq!("1476794096.789000".to_owned(),
+                   GroongaTime::from_microseconds(1476794096789000).unix_time());
+        assert_eq!("-0.500000".to_owned(),
+                   GroongaTime::from_microseconds(-500000).unix_time());
+    }
+}
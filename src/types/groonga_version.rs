@@ -0,0 +1,101 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A version of Groonga server such as `6.0.3`.
+///
+/// It is compared in order of major, minor and micro version.
+#[derive (Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GroongaVersion {
+    major: u32,
+    minor: u32,
+    micro: u32,
+}
+
+impl GroongaVersion {
+    pub fn new(major: u32, minor: u32, micro: u32) -> GroongaVersion {
+        GroongaVersion {
+            major: major,
+            minor: minor,
+            micro: micro,
+        }
+    }
+
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    pub fn micro(&self) -> u32 {
+        self.micro
+    }
+}
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum GroongaVersionError {
+    Empty,
+    InvalidFormat(String),
+}
+
+/// Accepts `6.0.3`, `6.0` and development versions such as `7.0.1-12-gabcdef`.
+impl FromStr for GroongaVersion {
+    type Err = GroongaVersionError;
+    fn from_str(s: &str) -> Result<GroongaVersion, GroongaVersionError> {
+        if s == "" {
+            return Err(GroongaVersionError::Empty);
+        }
+        let release = s.split('-').next().unwrap_or("");
+        let mut numbers = vec![];
+        for number in release.split('.') {
+            match number.parse() {
+                Ok(n) => numbers.push(n),
+                Err(_) => return Err(GroongaVersionError::InvalidFormat(s.to_owned())),
+            }
+        }
+        match numbers.len() {
+            2 => Ok(GroongaVersion::new(numbers[0], numbers[1], 0)),
+            3 => Ok(GroongaVersion::new(numbers[0], numbers[1], numbers[2])),
+            _ => Err(GroongaVersionError::InvalidFormat(s.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for GroongaVersion {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(GroongaVersion::new(6, 0, 3), FromStr::from_str("6.0.3").unwrap());
+        assert_eq!(GroongaVersion::new(7, 1, 0), FromStr::from_str("7.1").unwrap());
+        assert_eq!(GroongaVersion::new(7, 0, 1),
+                   FromStr::from_str("7.0.1-12-gabcdef").unwrap());
+        assert_eq!(Err(GroongaVersionError::Empty),
+                   GroongaVersion::from_str(""));
+        assert_eq!(Err(GroongaVersionError::InvalidFormat("6.x".to_owned())),
+                   GroongaVersion::from_str("6.x"));
+        assert_eq!(Err(GroongaVersionError::InvalidFormat("6".to_owned())),
+                   GroongaVersion::from_str("6"));
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("6.0.3".to_owned(), format!("{}", GroongaVersion::new(6, 0, 3)));
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(GroongaVersion::new(6, 0, 3) < GroongaVersion::new(6, 0, 10));
+        assert!(GroongaVersion::new(6, 1, 0) > GroongaVersion::new(6, 0, 10));
+        assert!(GroongaVersion::new(7, 0, 0) > GroongaVersion::new(6, 9, 9));
+    }
+}
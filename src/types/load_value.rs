@@ -0,0 +1,79 @@
+use serde_json::Value;
+use types::geo_point::GeoPoint;
+
+/// A value of a record which is loaded with `load`.
+///
+/// A reference is serialized as the key of the referred record.
+#[derive (Clone, PartialEq, Debug)]
+pub enum LoadValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Text(String),
+    GeoPoint(GeoPoint),
+    /// A key of the referred record.
+    Reference(String),
+    /// Keys of the referred records.
+    References(Vec<String>),
+    /// Keys of the referred records and their weights for `WithWeight` column.
+    WeightedReferences(Vec<(String, u32)>),
+}
+
+impl LoadValue {
+    pub fn to_json(&self) -> String {
+        match *self {
+            LoadValue::Null => "null".to_owned(),
+            LoadValue::Bool(b) => b.to_string(),
+            LoadValue::Int(i) => i.to_string(),
+            LoadValue::UInt(u) => u.to_string(),
+            LoadValue::Float(f) => Value::from(f).to_string(),
+            LoadValue::Text(ref s) |
+            LoadValue::Reference(ref s) => Value::from(s.to_owned()).to_string(),
+            LoadValue::GeoPoint(ref point) => format!("\"{}\"", point),
+            LoadValue::References(ref keys) => {
+                let keys: Vec<String> =
+                    keys.iter().map(|k| Value::from(k.to_owned()).to_string()).collect();
+                format!("[{}]", keys.join(","))
+            }
+            LoadValue::WeightedReferences(ref keys) => {
+                let keys: Vec<String> = keys.iter()
+                    .map(|&(ref k, w)| format!("{}:{}", Value::from(k.to_owned()), w))
+                    .collect();
+                format!("{{{}}}", keys.join(","))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::geo_point::GeoPoint;
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!("null".to_owned(), LoadValue::Null.to_json());
+        assert_eq!("-1".to_owned(), LoadValue::Int(-1).to_json());
+        assert_eq!("1.5".to_owned(), LoadValue::Float(1.5).to_json());
+        assert_eq!("\"say \\\"hi\\\"\"".to_owned(),
+                   LoadValue::Text("say \"hi\"".to_owned()).to_json());
+        assert_eq!("\"128452975x503157902\"".to_owned(),
+                   LoadValue::GeoPoint(GeoPoint::from_milliseconds(128452975, 503157902))
+                       .to_json());
+    }
+
+    #[test]
+    fn test_references_to_json() {
+        assert_eq!("\"alice\"".to_owned(),
+                   LoadValue::Reference("alice".to_owned()).to_json());
+        assert_eq!("[\"rust\",\"groonga\"]".to_owned(),
+                   LoadValue::References(vec!["rust".to_owned(), "groonga".to_owned()])
+                       .to_json());
+        assert_eq!("{\"rust\":10,\"groonga\":5}".to_owned(),
+                   LoadValue::WeightedReferences(vec![("rust".to_owned(), 10),
+                                                      ("groonga".to_owned(), 5)])
+                       .to_json());
+    }
+}
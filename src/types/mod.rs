@@ -1,5 +1,6 @@
 pub mod table_flag_type;
 pub mod data_type;
+pub mod column_type;
 pub mod tokenizer_type;
 pub mod normalizer_type;
 pub mod token_filters_type;
@@ -9,3 +10,13 @@ pub mod select_query_flags;
 pub mod log_level_type;
 pub mod shutdown_mode_type;
 pub mod range_filter_type;
+pub mod stage_type;
+pub mod geo_point;
+pub mod load_value;
+pub mod geo_approximate_type;
+pub mod char_type;
+pub mod plugin;
+pub mod groonga_time;
+pub mod time_range;
+pub mod order_type;
+pub mod groonga_version;
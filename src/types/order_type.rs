@@ -0,0 +1,88 @@
+use std::fmt;
+use std::str::FromStr;
+use std::convert::AsRef;
+use self::OrderType::{Ascending, Descending, ExtOrderType};
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum OrderType {
+    Ascending,
+    Descending,
+    /// For future extensibility.
+    ExtOrderType(String),
+}
+
+impl AsRef<str> for OrderType {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Ascending => "ascending",
+            Descending => "descending",
+            ExtOrderType(ref s) => s.as_ref(),
+        }
+    }
+}
+
+#[derive (Debug)]
+pub enum OrderTypeError {
+    Empty,
+}
+
+impl FromStr for OrderType {
+    type Err = OrderTypeError;
+    fn from_str(s: &str) -> Result<OrderType, OrderTypeError> {
+        if s == "" {
+            Err(OrderTypeError::Empty)
+        } else {
+            Ok(match s {
+                "ascending" | "Ascending" | "ASCENDING" | "asc" => Ascending,
+                "descending" | "Descending" | "DESCENDING" | "desc" => Descending,
+                _ => ExtOrderType(s.to_owned()),
+            })
+        }
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            Ascending => "ascending",
+            Descending => "descending",
+            ExtOrderType(ref s) => s.as_ref(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(OrderType::Ascending, FromStr::from_str("ascending").unwrap());
+        assert_eq!(OrderType::Ascending, FromStr::from_str("asc").unwrap());
+        assert_eq!(OrderType::Descending, FromStr::from_str("DESCENDING").unwrap());
+        assert_eq!(OrderType::Descending, FromStr::from_str("desc").unwrap());
+        assert_eq!(OrderType::ExtOrderType("ADDED_TYPE".to_owned()),
+                   FromStr::from_str("ADDED_TYPE").unwrap());
+        let x: Result<OrderType, _> = FromStr::from_str("");
+        if let Err(OrderTypeError::Empty) = x {
+        } else {
+            panic!("An empty order type is invalid!")
+        }
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("descending".to_owned(), format!("{}", OrderType::Descending));
+        assert_eq!("ADDED_TYPE".to_owned(),
+                   format!("{}", OrderType::ExtOrderType("ADDED_TYPE".to_owned())));
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(OrderType::Ascending.as_ref(), "ascending");
+        assert_eq!(OrderType::Descending.as_ref(), "descending");
+        assert_eq!(OrderType::ExtOrderType("ADDED_TYPE".to_owned()).as_ref(),
+                   "ADDED_TYPE");
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+use std::convert::AsRef;
+use self::Plugin::{TokenFiltersStem, TokenFiltersStopWord, TokenizersMecab, NormalizersMySQL,
+                   FunctionsVector, FunctionsString, FunctionsTime, FunctionsNumber,
+                   FunctionsMath, FunctionsIndexColumn, QueryExpandersTSV, Sharding, ExtPlugin};
+
+/// A plugin which is registered with `plugin_register`.
+#[derive (Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Plugin {
+    TokenFiltersStem,
+    TokenFiltersStopWord,
+    TokenizersMecab,
+    /// Provided by groonga-normalizer-mysql.
+    NormalizersMySQL,
+    FunctionsVector,
+    FunctionsString,
+    FunctionsTime,
+    FunctionsNumber,
+    FunctionsMath,
+    FunctionsIndexColumn,
+    QueryExpandersTSV,
+    Sharding,
+    /// A custom plugin. It is a name which is relative to the plugins directory or
+    /// an absolute path.
+    ExtPlugin(String),
+}
+
+impl Plugin {
+    /// Resolves the plugin file path under `plugins_dir`.
+    ///
+    /// An absolute path is returned as is.
+    /// A suffix is appended when the plugin does not have it.
+    pub fn resolve(&self, plugins_dir: &str) -> String {
+        let name = self.as_ref();
+        let file = if name.ends_with(".so") || name.ends_with(".rb") {
+            name.to_owned()
+        } else if *self == Sharding {
+            format!("{}.rb", name)
+        } else {
+            format!("{}.so", name)
+        };
+        if file.starts_with('/') {
+            file
+        } else if plugins_dir.ends_with('/') {
+            format!("{}{}", plugins_dir, file)
+        } else {
+            format!("{}/{}", plugins_dir, file)
+        }
+    }
+}
+
+impl AsRef<str> for Plugin {
+    fn as_ref(&self) -> &str {
+        match *self {
+            TokenFiltersStem => "token_filters/stem",
+            TokenFiltersStopWord => "token_filters/stop_word",
+            TokenizersMecab => "tokenizers/mecab",
+            NormalizersMySQL => "normalizers/mysql",
+            FunctionsVector => "functions/vector",
+            FunctionsString => "functions/string",
+            FunctionsTime => "functions/time",
+            FunctionsNumber => "functions/number",
+            FunctionsMath => "functions/math",
+            FunctionsIndexColumn => "functions/index_column",
+            QueryExpandersTSV => "query_expanders/tsv",
+            Sharding => "sharding",
+            ExtPlugin(ref s) => s.as_ref(),
+        }
+    }
+}
+
+#[derive (Debug)]
+pub enum PluginError {
+    Empty,
+}
+
+impl FromStr for Plugin {
+    type Err = PluginError;
+    fn from_str(s: &str) -> Result<Plugin, PluginError> {
+        if s == "" {
+            Err(PluginError::Empty)
+        } else {
+            let name = if s.ends_with(".so") || s.ends_with(".rb") {
+                &s[..s.len() - 3]
+            } else {
+                s
+            };
+            Ok(match name {
+                "token_filters/stem" => TokenFiltersStem,
+                "token_filters/stop_word" => TokenFiltersStopWord,
+                "tokenizers/mecab" => TokenizersMecab,
+                "normalizers/mysql" => NormalizersMySQL,
+                "functions/vector" => FunctionsVector,
+                "functions/string" => FunctionsString,
+                "functions/time" => FunctionsTime,
+                "functions/number" => FunctionsNumber,
+                "functions/math" => FunctionsMath,
+                "functions/index_column" => FunctionsIndexColumn,
+                "query_expanders/tsv" => QueryExpandersTSV,
+                "sharding" => Sharding,
+                _ => ExtPlugin(s.to_owned()),
+            })
+        }
+    }
+}
+
+impl fmt::Display for Plugin {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Plugin::TokenFiltersStem,
+                   FromStr::from_str("token_filters/stem").unwrap());
+        assert_eq!(Plugin::Sharding, FromStr::from_str("sharding.rb").unwrap());
+        assert_eq!(Plugin::TokenizersMecab,
+                   FromStr::from_str("tokenizers/mecab.so").unwrap());
+        assert_eq!(Plugin::ExtPlugin("/opt/plugins/custom.so".to_owned()),
+                   FromStr::from_str("/opt/plugins/custom.so").unwrap());
+        let x: Result<Plugin, _> = FromStr::from_str("");
+        if let Err(PluginError::Empty) = x {
+        } else {
+            panic!("An empty plugin is invalid!")
+        }
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("token_filters/stop_word".to_owned(),
+                   format!("{}", Plugin::TokenFiltersStopWord));
+        assert_eq!("custom".to_owned(),
+                   format!("{}", Plugin::ExtPlugin("custom".to_owned())));
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(Plugin::FunctionsVector.as_ref(), "functions/vector");
+        assert_eq!(Plugin::ExtPlugin("custom".to_owned()).as_ref(), "custom");
+    }
+
+    #[test]
+    fn test_resolve() {
+        let plugins_dir = "/usr/lib/groonga/plugins/";
+        assert_eq!("/usr/lib/groonga/plugins/token_filters/stem.so".to_owned(),
+                   Plugin::TokenFiltersStem.resolve(plugins_dir));
+        assert_eq!("/usr/lib/groonga/plugins/sharding.rb".to_owned(),
+                   Plugin::Sharding.resolve(plugins_dir));
+        assert_eq!("/usr/lib/groonga/plugins/custom/ruby.rb".to_owned(),
+                   Plugin::ExtPlugin("custom/ruby.rb".to_owned()).resolve(plugins_dir));
+        assert_eq!("/opt/plugins/custom.so".to_owned(),
+                   Plugin::ExtPlugin("/opt/plugins/custom".to_owned()).resolve(plugins_dir));
+    }
+}
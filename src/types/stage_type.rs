@@ -0,0 +1,108 @@
+use std::fmt;
+use std::str::FromStr;
+use std::convert::AsRef;
+use self::StageType::{Initial, Filtered, Output, ExtStageType};
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum StageType {
+    Initial,
+    Filtered,
+    Output,
+    /// For future extensibility.
+    ExtStageType(String),
+}
+
+impl StageType {
+    /// Window functions are only available before output.
+    pub fn is_windowable(&self) -> bool {
+        match *self {
+            Initial | Filtered => true,
+            Output => false,
+            ExtStageType(_) => true,
+        }
+    }
+}
+
+impl AsRef<str> for StageType {
+    fn as_ref(&self) -> &str {
+        match *self {
+            Initial => "initial",
+            Filtered => "filtered",
+            Output => "output",
+            ExtStageType(ref s) => s.as_ref(),
+        }
+    }
+}
+
+#[derive (Debug)]
+pub enum StageTypeError {
+    Empty,
+}
+
+impl FromStr for StageType {
+    type Err = StageTypeError;
+    fn from_str(s: &str) -> Result<StageType, StageTypeError> {
+        if s == "" {
+            Err(StageTypeError::Empty)
+        } else {
+            Ok(match s {
+                "initial" | "Initial" | "INITIAL" => Initial,
+                "filtered" | "Filtered" | "FILTERED" => Filtered,
+                "output" | "Output" | "OUTPUT" => Output,
+                _ => ExtStageType(s.to_owned()),
+            })
+        }
+    }
+}
+
+impl fmt::Display for StageType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            Initial => "initial",
+            Filtered => "filtered",
+            Output => "output",
+            ExtStageType(ref s) => s.as_ref(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(StageType::Initial, FromStr::from_str("initial").unwrap());
+        assert_eq!(StageType::Filtered, FromStr::from_str("Filtered").unwrap());
+        assert_eq!(StageType::Output, FromStr::from_str("OUTPUT").unwrap());
+        assert_eq!(StageType::ExtStageType("ADDED_STAGE".to_owned()),
+                   FromStr::from_str("ADDED_STAGE").unwrap());
+        let x: Result<StageType, _> = FromStr::from_str("");
+        if let Err(StageTypeError::Empty) = x {
+        } else {
+            panic!("An empty stage type is invalid!")
+        }
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!("filtered".to_owned(), format!("{}", StageType::Filtered));
+        assert_eq!("ADDED_STAGE".to_owned(),
+                   format!("{}", StageType::ExtStageType("ADDED_STAGE".to_owned())));
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(StageType::Initial.as_ref(), "initial");
+        assert_eq!(StageType::ExtStageType("ADDED_STAGE".to_owned()).as_ref(),
+                   "ADDED_STAGE");
+    }
+
+    #[test]
+    fn test_is_windowable() {
+        assert!(StageType::Initial.is_windowable());
+        assert!(StageType::Filtered.is_windowable());
+        assert!(!StageType::Output.is_windowable());
+    }
+}
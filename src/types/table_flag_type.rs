@@ -1,7 +1,8 @@
 use std::fmt;
 use std::str::FromStr;
 use std::convert::AsRef;
-use self::TableFlagType::{NoKey, HashKey, PatKey, DatKey, KeyWithSIS, ExtTableFlagType};
+use self::TableFlagType::{NoKey, HashKey, PatKey, DatKey, KeyWithSIS, KeyLarge,
+                          ExtTableFlagType};
 
 #[derive (Clone, PartialEq, Eq, Debug)]
 pub enum TableFlagType {
@@ -10,6 +11,8 @@ pub enum TableFlagType {
     PatKey,
     DatKey,
     KeyWithSIS,
+    /// Expands the maximum total key size of a hash table.
+    KeyLarge,
     /// For future extensibility.
     ExtTableFlagType(String),
 }
@@ -22,6 +25,7 @@ impl AsRef<str> for TableFlagType {
             PatKey => "TABLE_PAT_KEY",
             DatKey => "TABLE_DAT_KEY",
             KeyWithSIS => "KEY_WITH_SIS",
+            KeyLarge => "KEY_LARGE",
             ExtTableFlagType(ref s) => s.as_ref(),
         }
     }
@@ -44,6 +48,7 @@ impl FromStr for TableFlagType {
                 "TABLE_PAT_KEY" | "PAT_KEY" | "PatKey" => PatKey,
                 "TABLE_DAT_KEY" | "DAT_KEY" | "DatKey" => DatKey,
                 "KEY_WITH_SIS" | "WithSIS" => KeyWithSIS,
+                "KEY_LARGE" | "KeyLarge" => KeyLarge,
                 _ => ExtTableFlagType(s.to_owned()),
             })
         }
@@ -58,11 +63,51 @@ impl fmt::Display for TableFlagType {
             PatKey => "TABLE_PAT_KEY",
             DatKey => "TABLE_DAT_KEY",
             KeyWithSIS => "KEY_WITH_SIS",
+            KeyLarge => "KEY_LARGE",
             ExtTableFlagType(ref s) => s.as_ref(),
         })
     }
 }
 
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum TableFlagsError {
+    /// The flags cannot be set together.
+    Conflict(TableFlagType, TableFlagType),
+    /// The first flag needs the second flag.
+    Requires(TableFlagType, TableFlagType),
+}
+
+/// Checks a combination of table flags.
+///
+/// Only one of `NoKey`, `HashKey`, `PatKey` and `DatKey` can be set and `HashKey` is assumed
+/// without them. `KeyWithSIS` needs `PatKey` and `KeyLarge` needs `HashKey`.
+pub fn validate_flags(flags: &[TableFlagType]) -> Result<(), TableFlagsError> {
+    let is_table_type = |flag: &TableFlagType| [NoKey, HashKey, PatKey, DatKey].contains(flag);
+    for (i, flag) in flags.iter().enumerate() {
+        for other in &flags[i + 1..] {
+            if flag != other && is_table_type(flag) && is_table_type(other) {
+                return Err(TableFlagsError::Conflict(flag.clone(), other.clone()));
+            }
+        }
+    }
+    let table_type = flags.iter()
+        .find(|flag| is_table_type(flag))
+        .cloned()
+        .unwrap_or(HashKey);
+    for flag in flags {
+        match *flag {
+            KeyWithSIS if table_type != PatKey => {
+                return Err(TableFlagsError::Requires(KeyWithSIS, PatKey));
+            }
+            KeyLarge if table_type != HashKey => {
+                return Err(TableFlagsError::Requires(KeyLarge, HashKey));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -74,6 +119,8 @@ mod test {
         assert_eq!(TableFlagType::NoKey, FromStr::from_str("NO_KEY").unwrap());
         assert_eq!(TableFlagType::NoKey,
                    FromStr::from_str("TABLE_NO_KEY").unwrap());
+        assert_eq!(TableFlagType::KeyLarge,
+                   FromStr::from_str("KEY_LARGE").unwrap());
         assert_eq!(TableFlagType::ExtTableFlagType("ADDED_TYPE".to_owned()),
                    FromStr::from_str("ADDED_TYPE").unwrap());
         let x: Result<TableFlagType, _> = FromStr::from_str("");
@@ -95,7 +142,25 @@ mod test {
     #[test]
     fn test_as_str() {
         assert_eq!(TableFlagType::DatKey.as_ref(), "TABLE_DAT_KEY");
+        assert_eq!(TableFlagType::KeyLarge.as_ref(), "KEY_LARGE");
         assert_eq!(TableFlagType::ExtTableFlagType("ADDED_TYPE".to_owned()).as_ref(),
                    "ADDED_TYPE");
     }
+
+    #[test]
+    fn test_validate_flags() {
+        use super::TableFlagType::*;
+        assert_eq!(Ok(()), validate_flags(&[PatKey, KeyWithSIS]));
+        assert_eq!(Ok(()), validate_flags(&[HashKey, KeyLarge]));
+        assert_eq!(Ok(()), validate_flags(&[KeyLarge]));
+        assert_eq!(Ok(()), validate_flags(&[]));
+        assert_eq!(Err(TableFlagsError::Conflict(HashKey, PatKey)),
+                   validate_flags(&[HashKey, PatKey]));
+        assert_eq!(Err(TableFlagsError::Conflict(NoKey, DatKey)),
+                   validate_flags(&[NoKey, KeyWithSIS, DatKey]));
+        assert_eq!(Err(TableFlagsError::Requires(KeyWithSIS, PatKey)),
+                   validate_flags(&[DatKey, KeyWithSIS]));
+        assert_eq!(Err(TableFlagsError::Requires(KeyLarge, HashKey)),
+                   validate_flags(&[PatKey, KeyLarge]));
+    }
 }
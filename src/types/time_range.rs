@@ -0,0 +1,64 @@
+use types::groonga_time::GroongaTime;
+use types::range_filter_type::RangeFilterType;
+
+/// A range of shard key for `logical_*` commands.
+///
+/// Both borders are `Include` by default like Groonga does.
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub struct TimeRange {
+    pub min: GroongaTime,
+    pub min_border: RangeFilterType,
+    pub max: GroongaTime,
+    pub max_border: RangeFilterType,
+}
+
+impl TimeRange {
+    pub fn new(min: GroongaTime, max: GroongaTime) -> TimeRange {
+        TimeRange {
+            min: min,
+            min_border: RangeFilterType::Include,
+            max: max,
+            max_border: RangeFilterType::Include,
+        }
+    }
+
+    pub fn min_border(mut self, border: RangeFilterType) -> TimeRange {
+        self.min_border = border;
+        self
+    }
+
+    pub fn max_border(mut self, border: RangeFilterType) -> TimeRange {
+        self.max_border = border;
+        self
+    }
+
+    /// Returns `min`, `min_border`, `max` and `max_border` arguments.
+    ///
+    /// `min` and `max` are seconds since the UNIX epoch,
+    /// which do not depend on the server's time zone.
+    pub fn to_arguments(&self) -> Vec<(String, String)> {
+        vec![("min".to_string(), format!("'{}'", self.min.unix_time())),
+             ("min_border".to_string(), format!("'{}'", self.min_border)),
+             ("max".to_string(), format!("'{}'", self.max.unix_time())),
+             ("max_border".to_string(), format!("'{}'", self.max_border))]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use types::groonga_time::GroongaTime;
+    use types::range_filter_type::RangeFilterType;
+
+    #[test]
+    fn test_to_arguments() {
+        let range = TimeRange::new(GroongaTime::from_date(2016, 10, 18),
+                                   GroongaTime::from_date(2016, 10, 19))
+            .max_border(RangeFilterType::Exclude);
+        let expected = vec![("min".to_string(), "'1476748800'".to_string()),
+                            ("min_border".to_string(), "'include'".to_string()),
+                            ("max".to_string(), "'1476835200'".to_string()),
+                            ("max_border".to_string(), "'exclude'".to_string())];
+        assert_eq!(expected, range.to_arguments());
+    }
+}
@@ -42,3 +42,40 @@ pub fn labeled_window_key(label: String, key: String) -> String {
     let string = format!("columns[{}].window.{}", label.to_owned(), key.to_owned());
     string.clone()
 }
+
+#[inline]
+pub fn split_identifiers(expression: String) -> Vec<String> {
+    let mut identifiers: Vec<String> = vec![];
+    let mut current = String::new();
+    let mut in_literal = false;
+    let mut escaped = false;
+    for c in expression.chars() {
+        if in_literal {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_literal = false;
+            }
+            continue;
+        }
+        if c.is_alphanumeric() || c == '_' {
+            current.push(c);
+            continue;
+        }
+        if c == '"' {
+            in_literal = true;
+        }
+        if !current.is_empty() {
+            identifiers.push(current.clone());
+            current.clear();
+        }
+    }
+    if !current.is_empty() {
+        identifiers.push(current);
+    }
+    identifiers.into_iter()
+        .filter(|i| !i.starts_with(|c: char| c.is_numeric()))
+        .collect()
+}
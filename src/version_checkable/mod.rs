@@ -0,0 +1,476 @@
+use command::{Command, Query};
+use types::groonga_version::GroongaVersion;
+
+#[derive (Clone, PartialEq, Eq, Debug)]
+pub enum VersionCheckError {
+    /// The command is not available.
+    /// It holds the command and the version which introduced it.
+    UnsupportedCommand(Command, GroongaVersion),
+    /// The parameter is not available.
+    /// It holds the parameter and the version which introduced it.
+    UnsupportedParameter(String, GroongaVersion),
+    /// The flag value is not available.
+    /// It holds the flag and the version which introduced it.
+    UnsupportedFlag(String, GroongaVersion),
+}
+
+pub trait VersionCheckable {
+    /// Checks whether Groonga of `version` accepts this command and all of its parameters.
+    fn check_version(&self, version: &GroongaVersion) -> Result<(), VersionCheckError>;
+}
+
+macro_rules! version_checkable {
+    ($($t:ty), +) => (
+        $(impl $crate::version_checkable::VersionCheckable for $t {
+            fn check_version(&self,
+                             version: &$crate::types::groonga_version::GroongaVersion)
+                             -> Result<(), $crate::version_checkable::VersionCheckError> {
+                let (command, query) = self.clone().build();
+                $crate::version_checkable::check(&command, &query, version)
+            }
+        })+
+    )
+}
+
+type Since = (u32, u32, u32);
+
+// The oldest version which is tracked.
+// Commands and parameters which are available in it are listed with this version.
+const BASE: Since = (4, 0, 0);
+
+// Commands in `Command` and the versions which introduced them.
+const COMMANDS: &[(&str, Since)] = &[("cache_limit", BASE),
+                                     ("column_create", BASE),
+                                     ("column_list", BASE),
+                                     ("column_remove", BASE),
+                                     ("column_rename", BASE),
+                                     ("delete", BASE),
+                                     ("dump", BASE),
+                                     ("load", BASE),
+                                     ("log_level", BASE),
+                                     ("log_put", BASE),
+                                     ("log_reopen", BASE),
+                                     ("normalize", BASE),
+                                     ("normalizer_list", BASE),
+                                     ("select", BASE),
+                                     ("shutdown", BASE),
+                                     ("status", BASE),
+                                     ("table_create", BASE),
+                                     ("table_list", BASE),
+                                     ("table_remove", BASE),
+                                     ("table_rename", BASE),
+                                     ("tokenize", BASE),
+                                     ("tokenizer_list", BASE),
+                                     ("request_cancel", (4, 0, 9)),
+                                     ("truncate", (4, 0, 9)),
+                                     ("logical_count", (5, 0, 0)),
+                                     ("logical_range_filter", (5, 0, 0)),
+                                     ("plugin_register", (5, 0, 1)),
+                                     ("plugin_unregister", (5, 0, 1)),
+                                     ("table_tokenize", (5, 0, 3)),
+                                     ("io_flush", (5, 0, 5)),
+                                     ("logical_select", (5, 0, 5)),
+                                     ("logical_table_remove", (5, 0, 5)),
+                                     ("object_exist", (5, 0, 6)),
+                                     ("database_unmap", (5, 0, 7)),
+                                     ("logical_shard_list", (5, 0, 7)),
+                                     ("thread_limit", (5, 0, 7)),
+                                     ("schema", (5, 0, 9)),
+                                     ("column_copy", (5, 1, 0)),
+                                     ("reindex", (5, 1, 0)),
+                                     ("object_inspect", (6, 0, 0)),
+                                     ("object_remove", (6, 0, 0))];
+
+// Parameters of each command and the versions which introduced them.
+// An empty command means parameters which are accepted by all commands.
+// `[]` matches any label such as `drilldowns[tag]`.
+const PARAMETERS: &[(&str, &str, Since)] =
+    &[("", "command_version", BASE),
+      ("", "output_type", BASE),
+      ("", "request_id", (4, 0, 9)),
+      ("", "request_timeout", (6, 1, 1)),
+      ("cache_limit", "max", BASE),
+      ("column_copy", "from_table", (5, 1, 0)),
+      ("column_copy", "from_name", (5, 1, 0)),
+      ("column_copy", "to_table", (5, 1, 0)),
+      ("column_copy", "to_name", (5, 1, 0)),
+      ("column_create", "table", BASE),
+      ("column_create", "name", BASE),
+      ("column_create", "flags", BASE),
+      ("column_create", "type", BASE),
+      ("column_create", "source", BASE),
+      ("column_list", "table", BASE),
+      ("column_remove", "table", BASE),
+      ("column_remove", "name", BASE),
+      ("column_rename", "table", BASE),
+      ("column_rename", "name", BASE),
+      ("column_rename", "new_name", BASE),
+      ("delete", "table", BASE),
+      ("delete", "key", BASE),
+      ("delete", "id", BASE),
+      ("delete", "filter", BASE),
+      ("dump", "tables", BASE),
+      ("dump", "dump_plugins", (5, 0, 3)),
+      ("dump", "dump_schema", (5, 0, 3)),
+      ("dump", "dump_records", (5, 0, 3)),
+      ("dump", "dump_indexes", (5, 0, 3)),
+      ("io_flush", "target_name", (5, 0, 5)),
+      ("io_flush", "recursive", (5, 0, 5)),
+      ("load", "values", BASE),
+      ("load", "table", BASE),
+      ("load", "columns", BASE),
+      ("load", "ifexists", BASE),
+      ("load", "input_type", BASE),
+      ("load", "each", BASE),
+      ("log_level", "level", BASE),
+      ("log_put", "level", BASE),
+      ("log_put", "message", BASE),
+      ("normalize", "normalizer", BASE),
+      ("normalize", "string", BASE),
+      ("normalize", "flags", BASE),
+      ("object_exist", "name", (5, 0, 6)),
+      ("object_inspect", "name", (6, 0, 0)),
+      ("object_remove", "name", (6, 0, 0)),
+      ("object_remove", "force", (6, 0, 0)),
+      ("plugin_register", "name", (5, 0, 1)),
+      ("plugin_unregister", "name", (5, 0, 1)),
+      ("reindex", "target_name", (5, 1, 0)),
+      ("request_cancel", "id", (4, 0, 9)),
+      ("select", "table", BASE),
+      ("select", "match_columns", BASE),
+      ("select", "query", BASE),
+      ("select", "filter", BASE),
+      ("select", "scorer", BASE),
+      ("select", "sortby", BASE),
+      ("select", "output_columns", BASE),
+      ("select", "offset", BASE),
+      ("select", "limit", BASE),
+      ("select", "drilldown", BASE),
+      ("select", "drilldown_sortby", BASE),
+      ("select", "drilldown_output_columns", BASE),
+      ("select", "drilldown_offset", BASE),
+      ("select", "drilldown_limit", BASE),
+      ("select", "cache", BASE),
+      ("select", "match_escalation_threshold", BASE),
+      ("select", "query_expander", BASE),
+      ("select", "query_flags", BASE),
+      ("select", "adjuster", BASE),
+      ("select", "drilldowns[]", (5, 0, 1)),
+      ("select", "drilldowns[].keys", (5, 0, 1)),
+      ("select", "drilldowns[].sortby", (5, 0, 1)),
+      ("select", "drilldowns[].output_columns", (5, 0, 1)),
+      ("select", "drilldowns[].offset", (5, 0, 1)),
+      ("select", "drilldowns[].limit", (5, 0, 1)),
+      ("select", "drilldown_calc_types", (6, 0, 0)),
+      ("select", "drilldown_calc_target", (6, 0, 0)),
+      ("select", "drilldowns[].calc_types", (6, 0, 0)),
+      ("select", "drilldowns[].calc_target", (6, 0, 0)),
+      ("select", "sort_keys", (6, 0, 1)),
+      ("select", "drilldown_sort_keys", (6, 0, 1)),
+      ("select", "drilldowns[].sort_keys", (6, 0, 1)),
+      ("select", "columns[]", (6, 0, 5)),
+      ("select", "columns[].stage", (6, 0, 5)),
+      ("select", "columns[].type", (6, 0, 5)),
+      ("select", "columns[].flags", (6, 0, 5)),
+      ("select", "columns[].value", (6, 0, 5)),
+      ("select", "columns[].sort_keys", (6, 0, 5)),
+      ("select", "columns[].window", (6, 0, 8)),
+      ("select", "drilldowns[].table", (6, 1, 5)),
+      ("select", "drilldowns[].columns[]", (6, 1, 5)),
+      ("select", "drilldowns[].filter", (6, 1, 5)),
+      ("select", "drilldowns[].max_n_target_records", (7, 0, 0)),
+      ("select", "drilldown_max_n_target_records", (7, 0, 0)),
+      ("select", "post_filter", (7, 1, 0)),
+      ("select", "match_escalation", (8, 0, 1)),
+      ("select", "load_table", (9, 1, 2)),
+      ("select", "load_columns", (9, 1, 2)),
+      ("select", "load_values", (9, 1, 2)),
+      ("shutdown", "mode", BASE),
+      ("table_create", "name", BASE),
+      ("table_create", "flags", BASE),
+      ("table_create", "key_type", BASE),
+      ("table_create", "value_type", BASE),
+      ("table_create", "default_tokenizer", BASE),
+      ("table_create", "normalizer", BASE),
+      ("table_create", "token_filters", BASE),
+      ("table_list", "prefix", BASE),
+      ("table_remove", "name", BASE),
+      ("table_remove", "dependent", (6, 0, 1)),
+      ("table_rename", "name", BASE),
+      ("table_rename", "new_name", BASE),
+      ("table_tokenize", "table", (5, 0, 3)),
+      ("table_tokenize", "string", (5, 0, 3)),
+      ("table_tokenize", "flags", (5, 0, 3)),
+      ("table_tokenize", "mode", (5, 0, 3)),
+      ("table_tokenize", "index_column", (5, 0, 3)),
+      ("thread_limit", "max", (5, 0, 7)),
+      ("tokenize", "tokenizer", BASE),
+      ("tokenize", "string", BASE),
+      ("tokenize", "normalizer", BASE),
+      ("tokenize", "flags", BASE),
+      ("tokenize", "mode", BASE),
+      ("tokenize", "token_filters", BASE),
+      ("truncate", "target_name", (4, 0, 9)),
+      ("logical_count", "logical_table", (5, 0, 0)),
+      ("logical_count", "shard_key", (5, 0, 0)),
+      ("logical_count", "min", (5, 0, 0)),
+      ("logical_count", "min_border", (5, 0, 0)),
+      ("logical_count", "max", (5, 0, 0)),
+      ("logical_count", "max_border", (5, 0, 0)),
+      ("logical_count", "filter", (5, 0, 0)),
+      ("logical_count", "query", (5, 0, 0)),
+      ("logical_count", "match_columns", (5, 0, 0)),
+      ("logical_count", "columns[]", (7, 0, 2)),
+      ("logical_count", "post_filter", (7, 1, 0)),
+      ("logical_range_filter", "logical_table", (5, 0, 0)),
+      ("logical_range_filter", "shard_key", (5, 0, 0)),
+      ("logical_range_filter", "min", (5, 0, 0)),
+      ("logical_range_filter", "min_border", (5, 0, 0)),
+      ("logical_range_filter", "max", (5, 0, 0)),
+      ("logical_range_filter", "max_border", (5, 0, 0)),
+      ("logical_range_filter", "order", (5, 0, 0)),
+      ("logical_range_filter", "filter", (5, 0, 0)),
+      ("logical_range_filter", "offset", (5, 0, 0)),
+      ("logical_range_filter", "limit", (5, 0, 0)),
+      ("logical_range_filter", "output_columns", (5, 0, 0)),
+      ("logical_range_filter", "use_range_index", (5, 0, 8)),
+      ("logical_select", "logical_table", (5, 0, 5)),
+      ("logical_select", "shard_key", (5, 0, 5)),
+      ("logical_select", "min", (5, 0, 5)),
+      ("logical_select", "min_border", (5, 0, 5)),
+      ("logical_select", "max", (5, 0, 5)),
+      ("logical_select", "max_border", (5, 0, 5)),
+      ("logical_select", "filter", (5, 0, 5)),
+      ("logical_select", "sortby", (5, 0, 5)),
+      ("logical_select", "output_columns", (5, 0, 5)),
+      ("logical_select", "offset", (5, 0, 5)),
+      ("logical_select", "limit", (5, 0, 5)),
+      ("logical_select", "drilldown", (5, 0, 5)),
+      ("logical_select", "drilldown_sortby", (5, 0, 5)),
+      ("logical_select", "drilldown_output_columns", (5, 0, 5)),
+      ("logical_select", "drilldown_offset", (5, 0, 5)),
+      ("logical_select", "drilldown_limit", (5, 0, 5)),
+      ("logical_select", "drilldown_calc_types", (5, 0, 5)),
+      ("logical_select", "drilldown_calc_target", (5, 0, 5)),
+      ("logical_select", "drilldowns[]", (5, 0, 5)),
+      ("logical_select", "match_columns", (5, 0, 5)),
+      ("logical_select", "query", (5, 0, 5)),
+      ("logical_select", "query_expander", (5, 0, 5)),
+      ("logical_select", "query_flags", (5, 0, 5)),
+      ("logical_select", "match_escalation_threshold", (5, 0, 5)),
+      ("logical_select", "scorer", (5, 0, 5)),
+      ("logical_select", "adjuster", (5, 0, 5)),
+      ("logical_select", "cache", (5, 0, 5)),
+      ("logical_select", "sort_keys", (6, 0, 1)),
+      ("logical_select", "drilldown_sort_keys", (6, 0, 1)),
+      ("logical_select", "drilldowns[].sort_keys", (6, 0, 1)),
+      ("logical_select", "columns[]", (7, 0, 2)),
+      ("logical_select", "drilldowns[].columns[]", (7, 0, 2)),
+      ("logical_select", "drilldowns[].filter", (7, 0, 2)),
+      ("logical_select", "post_filter", (7, 1, 0)),
+      ("logical_select", "match_escalation", (8, 0, 1)),
+      ("logical_select", "load_table", (9, 1, 2)),
+      ("logical_select", "load_columns", (9, 1, 2)),
+      ("logical_select", "load_values", (9, 1, 2)),
+      ("logical_shard_list", "logical_table", (5, 0, 7)),
+      ("logical_table_remove", "logical_table", (5, 0, 5)),
+      ("logical_table_remove", "shard_key", (5, 0, 5)),
+      ("logical_table_remove", "min", (5, 0, 5)),
+      ("logical_table_remove", "min_border", (5, 0, 5)),
+      ("logical_table_remove", "max", (5, 0, 5)),
+      ("logical_table_remove", "max_border", (5, 0, 5)),
+      ("logical_table_remove", "dependent", (6, 0, 1))];
+
+// Flag values which were introduced after their `flags` parameters.
+// They are checked in `flags` and labeled `*.flags` parameters of any command.
+const FLAGS: &[(&str, Since)] = &[("INDEX_SMALL", (6, 0, 8)),
+                                  ("INDEX_MEDIUM", (6, 0, 8)),
+                                  ("COMPRESS_ZSTD", (6, 1, 1)),
+                                  ("KEY_LARGE", (6, 1, 1)),
+                                  ("WEIGHT_FLOAT32", (10, 0, 3)),
+                                  ("MISSING_ADD", (12, 0, 2)),
+                                  ("MISSING_IGNORE", (12, 0, 2)),
+                                  ("MISSING_NIL", (12, 0, 2)),
+                                  ("INVALID_ERROR", (12, 0, 2)),
+                                  ("INVALID_WARN", (12, 0, 2)),
+                                  ("INVALID_IGNORE", (12, 0, 2))];
+
+fn version(since: Since) -> GroongaVersion {
+    let (major, minor, micro) = since;
+    GroongaVersion::new(major, minor, micro)
+}
+
+// Removes labels from a parameter: `drilldowns[tag].keys` into `drilldowns[].keys`.
+fn pattern(parameter: &str) -> String {
+    let mut pattern = String::new();
+    let mut in_label = false;
+    for c in parameter.chars() {
+        match c {
+            '[' => {
+                in_label = true;
+                pattern.push(c);
+            }
+            ']' => {
+                in_label = false;
+                pattern.push(c);
+            }
+            _ if in_label => {}
+            _ => pattern.push(c),
+        }
+    }
+    pattern
+}
+
+/// The version which introduced `command`.
+/// Commands which are not in the table such as `Command::Extension` return `0.0.0`.
+pub fn command_since(command: &Command) -> GroongaVersion {
+    COMMANDS.iter()
+        .find(|&&(name, _)| name == command.as_ref())
+        .map(|&(_, since)| version(since))
+        .unwrap_or_else(|| GroongaVersion::new(0, 0, 0))
+}
+
+/// The version which introduced `parameter` of `command`.
+///
+/// Returns `None` when the parameter is not in the table.
+pub fn parameter_since(command: &Command, parameter: &str) -> Option<GroongaVersion> {
+    let pattern = pattern(parameter);
+    PARAMETERS.iter()
+        .filter(|&&(name, _, _)| name.is_empty() || name == command.as_ref())
+        .filter(|&&(_, key, _)| pattern == key || pattern.starts_with(&format!("{}.", key)))
+        .map(|&(_, _, since)| version(since))
+        .max()
+}
+
+/// The version which introduced `flag` such as `INDEX_SMALL`.
+///
+/// Returns `None` when the flag is available since its `flags` parameter is introduced.
+pub fn flag_since(flag: &str) -> Option<GroongaVersion> {
+    FLAGS.iter()
+        .find(|&&(name, _)| name == flag)
+        .map(|&(_, since)| version(since))
+}
+
+fn is_flags(parameter: &str) -> bool {
+    parameter == "flags" || parameter.ends_with(".flags")
+}
+
+/// Checks the command, parameters and flags which are built by a query builder.
+///
+/// Parameters are checked in order of their names, so that the same error is reported
+/// for the same query.
+pub fn check(command: &Command,
+             query: &Query,
+             version: &GroongaVersion)
+             -> Result<(), VersionCheckError> {
+    let since = command_since(command);
+    if *version < since {
+        return Err(VersionCheckError::UnsupportedCommand(command.clone(), since));
+    }
+    let mut parameters: Vec<&(String, String)> = query.iter().collect();
+    parameters.sort();
+    for parameter in parameters {
+        let (ref key, ref value) = *parameter;
+        if let Some(since) = parameter_since(command, key) {
+            if *version < since {
+                return Err(VersionCheckError::UnsupportedParameter(key.to_owned(), since));
+            }
+        }
+        if !is_flags(key) {
+            continue;
+        }
+        for flag in value.trim_matches('\'').split('|').map(|flag| flag.trim()) {
+            if let Some(since) = flag_since(flag) {
+                if *version < since {
+                    return Err(VersionCheckError::UnsupportedFlag(flag.to_owned(), since));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use command::Command;
+    use types::groonga_version::GroongaVersion;
+
+    #[test]
+    fn test_pattern() {
+        assert_eq!("drilldowns[].columns[].stage".to_owned(),
+                   pattern("drilldowns[tag].columns[n].stage"));
+        assert_eq!("filter".to_owned(), pattern("filter"));
+    }
+
+    #[test]
+    fn test_parameter_since() {
+        assert_eq!(Some(GroongaVersion::new(6, 0, 5)),
+                   parameter_since(&Command::Select, "columns[price].stage"));
+        assert_eq!(Some(GroongaVersion::new(6, 0, 8)),
+                   parameter_since(&Command::Select, "columns[n].window.sort_keys"));
+        assert_eq!(Some(GroongaVersion::new(6, 1, 5)),
+                   parameter_since(&Command::Select, "drilldowns[tag].filter"));
+        assert_eq!(Some(GroongaVersion::new(4, 0, 0)),
+                   parameter_since(&Command::Select, "filter"));
+        assert_eq!(Some(GroongaVersion::new(4, 0, 9)),
+                   parameter_since(&Command::TableCreate, "request_id"));
+        assert_eq!(None, parameter_since(&Command::Select, "unknown"));
+        assert_eq!(None, parameter_since(&Command::TableCreate, "filter"));
+    }
+
+    #[test]
+    fn test_command_since() {
+        assert_eq!(GroongaVersion::new(4, 0, 0), command_since(&Command::Select));
+        assert_eq!(GroongaVersion::new(5, 1, 0), command_since(&Command::Reindex));
+        assert_eq!(GroongaVersion::new(0, 0, 0),
+                   command_since(&Command::Extension("added".to_owned())));
+    }
+
+    #[test]
+    fn test_check_flags() {
+        let query = vec![("table".to_owned(), "Terms".to_owned()),
+                         ("name".to_owned(), "index".to_owned()),
+                         ("flags".to_owned(), "COLUMN_INDEX|INDEX_SMALL".to_owned())];
+        assert_eq!(Ok(()),
+                   check(&Command::ColumnCreate, &query, &GroongaVersion::new(6, 0, 8)));
+        assert_eq!(Err(VersionCheckError::UnsupportedFlag("INDEX_SMALL".to_owned(),
+                                                          GroongaVersion::new(6, 0, 8))),
+                   check(&Command::ColumnCreate, &query, &GroongaVersion::new(6, 0, 7)));
+        let staged = vec![("columns[n].flags".to_owned(),
+                           "'COLUMN_SCALAR|MISSING_NIL'".to_owned())];
+        assert_eq!(Err(VersionCheckError::UnsupportedFlag("MISSING_NIL".to_owned(),
+                                                          GroongaVersion::new(12, 0, 2))),
+                   check(&Command::Select, &staged, &GroongaVersion::new(9, 0, 0)));
+    }
+
+    #[test]
+    fn test_check_in_parameter_order() {
+        let query = vec![("post_filter".to_owned(), "true".to_owned()),
+                         ("columns[price].stage".to_owned(), "initial".to_owned())];
+        let mut reversed = query.clone();
+        reversed.reverse();
+        let expected =
+            Err(VersionCheckError::UnsupportedParameter("columns[price].stage".to_owned(),
+                                                        GroongaVersion::new(6, 0, 5)));
+        assert_eq!(expected,
+                   check(&Command::Select, &query, &GroongaVersion::new(6, 0, 0)));
+        assert_eq!(expected,
+                   check(&Command::Select, &reversed, &GroongaVersion::new(6, 0, 0)));
+    }
+
+    #[test]
+    fn test_check() {
+        let query = vec![("table".to_owned(), "Entries".to_owned()),
+                         ("drilldowns[tag].keys".to_owned(), "tag".to_owned()),
+                         ("columns[price].stage".to_owned(), "initial".to_owned())];
+        assert_eq!(Ok(()),
+                   check(&Command::Select, &query, &GroongaVersion::new(6, 0, 5)));
+        assert_eq!(Err(VersionCheckError::UnsupportedParameter("columns[price].stage".to_owned(),
+                                                               GroongaVersion::new(6, 0, 5))),
+                   check(&Command::Select, &query, &GroongaVersion::new(6, 0, 3)));
+        assert_eq!(Err(VersionCheckError::UnsupportedCommand(Command::ObjectInspect,
+                                                             GroongaVersion::new(6, 0, 0))),
+                   check(&Command::ObjectInspect, &vec![], &GroongaVersion::new(5, 1, 2)));
+    }
+}